@@ -0,0 +1,114 @@
+//! This module defines the algebraic structures that coefficients can belong to.
+//!
+//! Polynomials and matrices only need a ring: addition, subtraction, multiplication, with zero and one elements.
+//! Polynomial products assume the multiplication to be commutative, and operations like euclidean division or power
+//! series inversion need a field. Integers are rings, while floats, complex numbers and prime fields are fields.
+
+#[cfg(test)]
+mod test;
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Custom trait for what can be an element of a ring
+///
+/// Elements can be sent and shared between threads, which the parallel feature relies on.
+pub trait Ring:
+    Copy
+    + Send
+    + Sync
+    + std::fmt::Debug
+    + std::fmt::Display
+    + PartialEq
+    + Default
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Neutral element of the addition
+    fn zero() -> Self;
+    /// Neutral element of the multiplication
+    fn one() -> Self;
+
+    /// Image of an integer in the ring, that is 1 + 1 + ... + 1, computed by doubling
+    fn from_integer(n: i64) -> Self {
+        let mut ret = Self::zero();
+        let mut power = Self::one();
+        let mut remaining = n.unsigned_abs();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                ret += power;
+            }
+            power += power;
+            remaining >>= 1;
+        }
+        if n < 0 {
+            -ret
+        } else {
+            ret
+        }
+    }
+}
+
+/// Custom trait for the elements of a ring whose multiplication is commutative
+pub trait CommutativeRing: Ring {}
+
+/// Custom trait for the elements of a field: every non-zero element can be divided by
+pub trait Field: CommutativeRing + Div<Output = Self> {}
+
+impl Ring for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+    fn one() -> Self {
+        1.0
+    }
+    fn from_integer(n: i64) -> Self {
+        n as f32
+    }
+}
+impl CommutativeRing for f32 {}
+impl Field for f32 {}
+
+impl Ring for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+    fn one() -> Self {
+        1.0
+    }
+    fn from_integer(n: i64) -> Self {
+        n as f64
+    }
+}
+impl CommutativeRing for f64 {}
+impl Field for f64 {}
+
+impl Ring for i64 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn from_integer(n: i64) -> Self {
+        n
+    }
+}
+impl CommutativeRing for i64 {}
+
+impl Ring for i128 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn from_integer(n: i64) -> Self {
+        n as i128
+    }
+}
+impl CommutativeRing for i128 {}
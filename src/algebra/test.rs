@@ -0,0 +1,23 @@
+use crate::algebra::Ring;
+use crate::complex::Complex;
+use crate::prime_field::Zp;
+
+#[test]
+fn ring_zero_one() {
+    assert_eq!(f32::zero(), 0.0);
+    assert_eq!(f64::one(), 1.0);
+    assert_eq!(i64::zero(), 0);
+    assert_eq!(i128::one(), 1);
+    assert_eq!(Complex::<f64>::one(), Complex::new(1.0, 0.0));
+    assert_eq!(Zp::<7>::zero().value(), 0);
+}
+
+#[test]
+fn ring_from_integer() {
+    assert_eq!(f64::from_integer(-12), -12.0);
+    assert_eq!(i128::from_integer(i64::MAX), i64::MAX as i128);
+    assert_eq!(Complex::<f32>::from_integer(5), Complex::new(5.0, 0.0));
+    assert_eq!(Zp::<7>::from_integer(12).value(), 5);
+    assert_eq!(Zp::<7>::from_integer(-1).value(), 6);
+    assert_eq!(Zp::<7>::from_integer(0).value(), 0);
+}
@@ -0,0 +1,125 @@
+//! This module implements the polar form and the elementary functions of complex numbers.
+//!
+//! Multivalued functions return their principal value: the branch cut of ln, sqrt and powf is the negative real
+//! axis, and the sign of a zero imaginary part selects the side of the cut. For instance sqrt(-4 + 0i) = 2i while
+//! sqrt(-4 - 0i) = -2i. Special values (infinities, NaN) follow the Annex G of the C standard.
+
+use crate::complex::{Complex, RealNumber};
+
+impl<T: RealNumber> Complex<T> {
+    /// Creates a complex number from its modulus and argument
+    pub fn from_polar(modulus: T, argument: T) -> Self {
+        // Avoids inf * 0 = NaN for an infinite modulus on the real axis
+        if argument == T::zero() {
+            return Self::new(modulus, argument);
+        }
+        Self::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    /// Modulus and argument of the complex number
+    pub fn to_polar(&self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    /// Modulus of the complex number, computed without intermediate overflow
+    pub fn abs(&self) -> T {
+        self.r.hypot(self.i)
+    }
+
+    /// Argument of the complex number, in [-pi, pi]. The sign of zeros is taken into account, so that the argument
+    /// of -1 - 0i is -pi.
+    pub fn arg(&self) -> T {
+        self.i.atan2(self.r)
+    }
+
+    /// Complex conjugate
+    pub fn conj(&self) -> Self {
+        Self::new(self.r, -self.i)
+    }
+
+    /// Exponential, computed as e^r (cos(i) + i sin(i))
+    pub fn exp(&self) -> Self {
+        let zero = T::zero();
+        if self.i == zero {
+            // Keeps the sign of the zero imaginary part, and avoids NaN for infinite real parts
+            return Self::new(self.r.exp(), self.i);
+        }
+        if self.r.is_infinite() && !self.i.is_finite() {
+            return if self.r.is_sign_negative() {
+                Self::new(zero, zero)
+            } else {
+                Self::new(self.r, T::nan())
+            };
+        }
+        Self::from_polar(self.r.exp(), self.i)
+    }
+
+    /// Principal value of the natural logarithm: ln|z| + i arg(z). The logarithm of zero is -inf.
+    pub fn ln(&self) -> Self {
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    /// Principal value of the square root, whose real part is non-negative.
+    ///
+    /// It is computed from sqrt((|r| + |z|) / 2) to avoid cancellations, and the number is scaled down first
+    /// when this sum would overflow, or scaled up when it would be subnormal or underflow to zero.
+    pub fn sqrt(&self) -> Self {
+        let zero = T::zero();
+        let two = T::from_integer(2);
+        if self.r == zero && self.i == zero {
+            return Self::new(zero, self.i);
+        }
+        if self.i.is_infinite() {
+            return Self::new(T::infinity(), self.i);
+        }
+        if self.r.is_nan() {
+            return Self::new(self.r, T::nan());
+        }
+        if self.r.is_infinite() {
+            // sqrt(+inf + yi) = +inf + 0i and sqrt(-inf + yi) = 0 + inf i, with NaN propagated from y
+            let imag_zero = if self.i.is_nan() { self.i } else { zero };
+            return if self.r.is_sign_negative() {
+                Self::new(imag_zero.abs(), T::infinity().copysign(self.i))
+            } else {
+                Self::new(self.r, imag_zero.copysign(self.i))
+            };
+        }
+
+        let half_sum = self.r.abs() / two + self.abs() / two;
+        if half_sum.is_infinite() {
+            let quarter = T::one() / T::from_integer(4);
+            let root = Self::new(self.r * quarter, self.i * quarter).sqrt();
+            return Self::new(root.r * two, root.i * two);
+        }
+        if half_sum < T::min_positive() {
+            // sqrt(2^60 z) = 2^30 sqrt(z), and 2^60 brings the smallest subnormal numbers back to normal ones
+            let scale = T::from_integer(1 << 30);
+            let root = Self::new(self.r * scale * scale, self.i * scale * scale).sqrt();
+            return Self::new(root.r / scale, root.i / scale);
+        }
+
+        let t = half_sum.sqrt();
+        if self.r.is_sign_negative() {
+            Self::new(self.i.abs() / (two * t), t.copysign(self.i))
+        } else {
+            Self::new(t, self.i / (two * t))
+        }
+    }
+
+    /// Principal value of the power z^n = e^(n ln(z)), for a real exponent n
+    pub fn powf(&self, n: T) -> Self {
+        let zero = T::zero();
+        if n == zero {
+            return Self::new(T::one(), zero);
+        }
+        if self.r == zero && self.i == zero {
+            return if n > zero {
+                Self::new(zero, zero)
+            } else {
+                Self::new(T::infinity(), zero)
+            };
+        }
+        let (modulus, argument) = self.to_polar();
+        Self::from_polar(modulus.powf(n), argument * n)
+    }
+}
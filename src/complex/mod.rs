@@ -5,51 +5,101 @@
 #[cfg(test)]
 mod test;
 
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+mod elementary;
+
+use crate::algebra::{CommutativeRing, Field, Ring};
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// number i, on a f32 representation
 pub const I_F32: Complex<f32> = Complex { r: 0.0, i: 1.0 };
 /// number i, on a f64 representation
 pub const I_F64: Complex<f64> = Complex { r: 0.0, i: 1.0 };
 
-/// Custom trait for what can be a number (real or complex)
-pub trait Number:
-    Copy
-    + std::fmt::Debug
-    + std::fmt::Display
-    + PartialEq
-    + From<f32>
-    + Default
-    + AddAssign
-    + SubAssign
-    + MulAssign
-    + Add<Output = Self>
-    + Sub<Output = Self>
-    + Mul<Output = Self>
-    + Neg<Output = Self>
-{
-}
-/// Custom trait for what can be a real number
-pub trait RealNumber:
-    Copy
-    + std::fmt::Debug
-    + std::fmt::Display
-    + PartialEq
-    + From<f32>
-    + Default
-    + AddAssign
-    + SubAssign
-    + Add<Output = Self>
-    + Sub<Output = Self>
-    + Mul<Output = Self>
-    + Neg<Output = Self>
-{
-}
-
-impl Number for f32 {}
-impl Number for f64 {}
-impl RealNumber for f32 {}
-impl RealNumber for f64 {}
+/// Custom trait for what can be a real number: the real and imaginary parts of complex numbers.
+///
+/// It gives access to the floating point functions needed by the complex elementary functions.
+pub trait RealNumber: Field + PartialOrd {
+    fn infinity() -> Self;
+    fn nan() -> Self;
+    /// Smallest positive normal value
+    fn min_positive() -> Self;
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+    fn abs(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+}
+
+/// Implements RealNumber for a primitive float type, by forwarding to its inherent methods
+macro_rules! impl_real_number {
+    ($float:ident) => {
+        impl RealNumber for $float {
+            fn infinity() -> Self {
+                $float::INFINITY
+            }
+            fn nan() -> Self {
+                $float::NAN
+            }
+            fn min_positive() -> Self {
+                $float::MIN_POSITIVE
+            }
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+            fn is_infinite(self) -> bool {
+                $float::is_infinite(self)
+            }
+            fn is_finite(self) -> bool {
+                $float::is_finite(self)
+            }
+            fn is_sign_negative(self) -> bool {
+                $float::is_sign_negative(self)
+            }
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+            fn copysign(self, sign: Self) -> Self {
+                $float::copysign(self, sign)
+            }
+            fn hypot(self, other: Self) -> Self {
+                $float::hypot(self, other)
+            }
+            fn atan2(self, other: Self) -> Self {
+                $float::atan2(self, other)
+            }
+            fn sqrt(self) -> Self {
+                $float::sqrt(self)
+            }
+            fn exp(self) -> Self {
+                $float::exp(self)
+            }
+            fn ln(self) -> Self {
+                $float::ln(self)
+            }
+            fn powf(self, n: Self) -> Self {
+                $float::powf(self, n)
+            }
+            fn sin(self) -> Self {
+                $float::sin(self)
+            }
+            fn cos(self) -> Self {
+                $float::cos(self)
+            }
+        }
+    };
+}
+impl_real_number!(f32);
+impl_real_number!(f64);
 
 /// Type representing complex numbers.
 /// It depends on a generic parameter which represents real part and imaginary part.
@@ -120,9 +170,15 @@ impl<T: RealNumber> From<&[T]> for Complex<T> {
 }
 
 /// Constructor from f32
-impl<T: RealNumber> From<f32> for Complex<T> {
+impl From<f32> for Complex<f32> {
     fn from(t: f32) -> Self {
-        Self::new(T::from(t), T::from(0.0))
+        Self::new(t, 0.0)
+    }
+}
+/// Constructor from f32
+impl From<f32> for Complex<f64> {
+    fn from(t: f32) -> Self {
+        Self::new(t.into(), 0.0)
     }
 }
 
@@ -190,6 +246,50 @@ impl<T: RealNumber> Mul for Complex<T> {
     }
 }
 
+/// Division with Smith's algorithm, which avoids the overflow of the squared modulus of the denominator.
+///
+/// When the result is NaN, infinities and zeros are recovered as in the Annex G of the C standard: a non-zero number
+/// divided by zero is infinite, an infinite number divided by a finite one is infinite, and a finite number divided by
+/// an infinite one is zero.
+impl<T: RealNumber> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, other: Complex<T>) -> Self {
+        let (a, b, c, d) = (self.r, self.i, other.r, other.i);
+        let (mut r, mut i) = if c.abs() >= d.abs() {
+            let ratio = d / c;
+            let denominator = c + d * ratio;
+            ((a + b * ratio) / denominator, (b - a * ratio) / denominator)
+        } else {
+            let ratio = c / d;
+            let denominator = c * ratio + d;
+            ((a * ratio + b) / denominator, (b * ratio - a) / denominator)
+        };
+
+        if r.is_nan() && i.is_nan() {
+            let zero = T::zero();
+            let one = T::one();
+            // Replaces infinite parts by +-1, and finite parts by +-0
+            let unit = |x: T| (if x.is_infinite() { one } else { zero }).copysign(x);
+
+            if c == zero && d == zero && (!a.is_nan() || !b.is_nan()) {
+                let infinity = T::infinity().copysign(c);
+                r = infinity * a;
+                i = infinity * b;
+            } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+                let (a, b) = (unit(a), unit(b));
+                r = T::infinity() * (a * c + b * d);
+                i = T::infinity() * (b * c - a * d);
+            } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+                let (c, d) = (unit(c), unit(d));
+                r = zero * (a * c + b * d);
+                i = zero * (b * c - a * d);
+            }
+        }
+        Self { r, i }
+    }
+}
+
 impl<T: RealNumber> AddAssign for Complex<T> {
     fn add_assign(&mut self, other: Complex<T>) {
         self.r += other.r;
@@ -223,5 +323,17 @@ impl<T: RealNumber> Neg for Complex<T> {
     }
 }
 
-impl<T: RealNumber> Number for Complex<T> {}
+impl<T: RealNumber> Ring for Complex<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+    fn from_integer(n: i64) -> Self {
+        Self::new(T::from_integer(n), T::zero())
+    }
+}
+impl<T: RealNumber> CommutativeRing for Complex<T> {}
+impl<T: RealNumber> Field for Complex<T> {}
 impl<T: RealNumber> crate::matrix::MatrixInput for Complex<T> {}
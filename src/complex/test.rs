@@ -141,3 +141,177 @@ fn complex_mulassign() {
 
     assert_eq!(a, Complex::new(-1.0, 5.0));
 }
+
+#[test]
+fn complex_div() {
+    let a = Complex::new(-1.0, 5.0);
+    let b = Complex::new(2.0, 3.0);
+
+    assert_eq!(a / b, Complex::new(1.0, 1.0));
+}
+
+#[test]
+fn complex_abs() {
+    assert_eq!(Complex::<f32>::new(3.0, -4.0).abs(), 5.0);
+    assert_eq!(Complex::<f64>::new(-3.0, 4.0).abs(), 5.0);
+}
+
+/// Compares complex numbers up to a relative tolerance
+fn assert_close(a: Complex<f64>, b: Complex<f64>) {
+    assert!(
+        (a - b).abs() <= 1e-12 * (1.0 + b.abs()),
+        "{} is not close to {}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn complex_div_special_values() {
+    let inf = f64::INFINITY;
+    let one = Complex::<f64>::new(1.0, 0.0);
+
+    // Smith's algorithm avoids overflowing c² + d²
+    let big = Complex::new(1e300, 1e300);
+    assert_close(big / big, one);
+
+    let q = Complex::<f64>::new(1.0, 2.0) / Complex::new(0.0, 0.0);
+    assert!(q.real().is_infinite() && q.imag().is_infinite());
+    let q = Complex::new(inf, 1.0) / Complex::new(2.0, 0.0);
+    assert!(q.real().is_infinite());
+    let q = Complex::new(1.0, 2.0) / Complex::new(inf, inf);
+    assert_eq!(q, Complex::new(0.0, 0.0));
+    let q = Complex::<f64>::new(0.0, 0.0) / Complex::new(0.0, 0.0);
+    assert!(q.real().is_nan() && q.imag().is_nan());
+}
+
+#[test]
+fn complex_arg_conj_polar() {
+    let z = Complex::<f64>::new(-1.0, 0.0);
+
+    assert_eq!(z.arg(), std::f64::consts::PI);
+    assert_eq!(Complex::<f64>::new(-1.0, -0.0).arg(), -std::f64::consts::PI);
+    assert_eq!(
+        Complex::<f32>::new(1.0, 2.0).conj(),
+        Complex::new(1.0, -2.0)
+    );
+
+    let z = Complex::<f64>::new(3.0, -4.0);
+    let (modulus, argument) = z.to_polar();
+    assert_eq!(modulus, 5.0);
+    assert_close(Complex::from_polar(modulus, argument), z);
+    assert_eq!(
+        Complex::from_polar(f64::INFINITY, 0.0),
+        Complex::new(f64::INFINITY, 0.0)
+    );
+}
+
+#[test]
+fn complex_exp_ln() {
+    let pi = std::f64::consts::PI;
+
+    assert_close(Complex::new(0.0, pi).exp(), Complex::new(-1.0, 0.0));
+    assert_close(
+        Complex::new(1.0, 0.0).exp(),
+        Complex::new(std::f64::consts::E, 0.0),
+    );
+    assert_close(Complex::new(-1.0, 0.0).ln(), Complex::new(0.0, pi));
+    assert_close(Complex::new(-1.0, -0.0).ln(), Complex::new(0.0, -pi));
+
+    let z = Complex::<f64>::new(0.3, -2.5);
+    assert_close(z.ln().exp(), z);
+
+    // Special values
+    let exp = Complex::<f64>::new(f64::INFINITY, 0.0).exp();
+    assert_eq!(exp, Complex::new(f64::INFINITY, 0.0));
+    let exp = Complex::<f64>::new(f64::NEG_INFINITY, f64::NAN).exp();
+    assert_eq!(exp, Complex::new(0.0, 0.0));
+    let exp = Complex::<f64>::new(f64::INFINITY, f64::INFINITY).exp();
+    assert!(exp.real().is_infinite() && exp.imag().is_nan());
+    let ln = Complex::<f64>::new(0.0, 0.0).ln();
+    assert_eq!(ln.real(), f64::NEG_INFINITY);
+    let ln = Complex::<f64>::new(f64::NEG_INFINITY, 1.0).ln();
+    assert_eq!(ln, Complex::new(f64::INFINITY, pi));
+
+    let z = Complex::<f32>::new(0.0, std::f32::consts::PI).exp();
+    assert!((z - Complex::new(-1.0, 0.0)).abs() < 1e-6);
+}
+
+#[test]
+fn complex_sqrt() {
+    assert_eq!(
+        Complex::<f64>::new(-4.0, 0.0).sqrt(),
+        Complex::new(0.0, 2.0)
+    );
+    assert_eq!(
+        Complex::<f64>::new(-4.0, -0.0).sqrt(),
+        Complex::new(0.0, -2.0)
+    );
+    assert_eq!(Complex::<f64>::new(0.0, 2.0).sqrt(), Complex::new(1.0, 1.0));
+    assert_eq!(Complex::<f32>::new(3.0, 4.0).sqrt(), Complex::new(2.0, 1.0));
+
+    let z = Complex::<f64>::new(-0.7, 1e-20);
+    let root = z.sqrt();
+    assert_close(root * root, z);
+    assert!(root.real() > 0.0);
+
+    // No overflow for huge values
+    let z = Complex::<f64>::new(f64::MAX, f64::MAX);
+    let root = z.sqrt();
+    assert!(root.real().is_finite() && root.imag().is_finite());
+    assert_close(
+        root / Complex::new(f64::MAX.sqrt(), 0.0),
+        Complex::new(1.0, 1.0).sqrt(),
+    );
+
+    // No underflow for tiny values, down to the smallest subnormal numbers
+    let tiny = 5e-324;
+    let root = Complex::<f64>::new(tiny, 0.0).sqrt();
+    assert_eq!(root, Complex::new(tiny.sqrt(), 0.0));
+    let root = Complex::<f64>::new(-tiny, 0.0).sqrt();
+    assert_eq!(root, Complex::new(0.0, tiny.sqrt()));
+    let root = Complex::<f64>::new(0.0, 2.0 * tiny).sqrt();
+    assert_close(root, Complex::new(tiny.sqrt(), tiny.sqrt()));
+    let z = Complex::<f64>::new(3e-310, -4e-310);
+    assert_close(
+        z.sqrt() * z.sqrt() / Complex::new(1e-310, 0.0),
+        Complex::new(3.0, -4.0),
+    );
+    let root = Complex::<f32>::new(1e-45, 0.0).sqrt();
+    assert_eq!(root, Complex::new(1e-45f32.sqrt(), 0.0));
+
+    // Special values
+    let inf = f64::INFINITY;
+    let zero = Complex::<f64>::new(-0.0, -0.0).sqrt();
+    assert_eq!(zero.real(), 0.0);
+    assert!(zero.imag().is_sign_negative());
+    assert_eq!(Complex::new(1.0, inf).sqrt(), Complex::new(inf, inf));
+    assert_eq!(Complex::new(-inf, 1.0).sqrt(), Complex::new(0.0, inf));
+    assert_eq!(Complex::new(inf, -1.0).sqrt(), Complex::new(inf, -0.0));
+    let root = Complex::new(f64::NAN, 1.0).sqrt();
+    assert!(root.real().is_nan() && root.imag().is_nan());
+}
+
+#[test]
+fn complex_powf() {
+    let z = Complex::<f64>::new(0.0, 1.0);
+
+    assert_close(z.powf(2.0), Complex::new(-1.0, 0.0));
+    assert_close(z.powf(0.5), Complex::new(0.5f64.sqrt(), 0.5f64.sqrt()));
+    assert_close(
+        Complex::new(-8.0, 0.0).powf(1.0 / 3.0),
+        Complex::new(1.0, 3f64.sqrt()),
+    );
+    assert_eq!(
+        Complex::<f64>::new(0.0, 0.0).powf(2.0),
+        Complex::new(0.0, 0.0)
+    );
+    assert_eq!(
+        Complex::<f64>::new(0.0, 0.0).powf(0.0),
+        Complex::new(1.0, 0.0)
+    );
+    assert_eq!(
+        Complex::<f64>::new(0.0, 0.0).powf(-1.0),
+        Complex::new(f64::INFINITY, 0.0)
+    );
+}
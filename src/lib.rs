@@ -3,10 +3,16 @@
 //!
 //! In the py_bindings module are all utilities necessary for Python binding, as we don't
 //! want them to spill over to the rest of the code
+//!
+//! With the parallel feature, large ffts and matrix products are spread over several threads
+//! with rayon. Results are the same as with a single thread.
 
+pub mod algebra;
 pub mod complex;
 pub mod matrix;
+mod parallel;
 pub mod polynomial;
+pub mod prime_field;
 
 #[cfg(feature = "pyo3")]
 pub mod py_bindings;
@@ -0,0 +1,133 @@
+use crate::matrix::*;
+use crate::parallel;
+use crate::polynomial::{check_ring, EvaluationDomain, RingKind};
+
+use std::ops::Mul;
+
+/// Matrix of modular polynomials kept in point-value representation: each entry is stored as its values at the
+/// roots of the modulus (see ModularArithmeticPolynomial::evaluate).
+///
+/// The product of two such matrices is a matrix product per root, with pointwise products of the values: it runs
+/// no transform at all. Converting the operands once, and the result back once, makes chains of products much
+/// cheaper than with a Matrix of polynomials, where every polynomial product runs three transforms.
+#[derive(Clone, Debug)]
+pub struct EvaluationMatrix<T: EvaluationDomain> {
+    /// Values of the entries, spanning columns before rows, each one contiguous
+    values: Vec<T::Value>,
+    rows: usize,
+    cols: usize,
+    modulus: usize,
+    kind: RingKind,
+}
+
+impl<T: EvaluationDomain> EvaluationMatrix<T> {
+    /// Converts a matrix of polynomials, which must all share the same modulus and kind of ring
+    pub fn from_matrix(
+        matrix: &Matrix<ModularArithmeticPolynomial<T>>,
+    ) -> Result<Self, MatrixError> {
+        matrix.check_entries()?;
+        let first = &matrix.arr[0];
+
+        let entries = parallel::map_indices(matrix.len(), |n| matrix.arr[n].evaluate());
+        let mut values = Vec::with_capacity(matrix.len() * first.modulus());
+        for entry in entries {
+            values.extend(entry?);
+        }
+
+        Ok(Self {
+            values,
+            rows: matrix.rows,
+            cols: matrix.cols,
+            modulus: first.modulus(),
+            kind: first.kind(),
+        })
+    }
+
+    /// Converts back to a matrix of polynomials, interpolating each entry
+    pub fn to_matrix(&self) -> MatrixResult<ModularArithmeticPolynomial<T>> {
+        let entries = parallel::map_indices(self.rows * self.cols, |n| {
+            ModularArithmeticPolynomial::<T>::interpolate(self.entry(n), self.kind)
+        });
+        Matrix::new(entries, self.rows, self.cols)
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Modulus of all the entries
+    pub fn modulus(&self) -> usize {
+        self.modulus
+    }
+
+    /// Kind of ring of all the entries
+    pub fn kind(&self) -> RingKind {
+        self.kind
+    }
+
+    /// Values of the entry at index n, spanning columns before rows
+    fn entry(&self, n: usize) -> &[T::Value] {
+        &self.values[n * self.modulus..(n + 1) * self.modulus]
+    }
+}
+
+/// Mul operation for matrices in point-value representation. This returns a Result because the shapes or the
+/// moduli of the matrices could be incompatible.
+impl<'a, T: EvaluationDomain> Mul for &'a EvaluationMatrix<T> {
+    type Output = Result<EvaluationMatrix<T>, MatrixError>;
+
+    fn mul(self, other: &'a EvaluationMatrix<T>) -> Result<EvaluationMatrix<T>, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "Uncompatible matrix shapes for multiplication, {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            )));
+        }
+        check_ring((self.modulus, self.kind), (other.modulus, other.kind))?;
+
+        let entries = parallel::map_indices(self.rows * other.cols, |n| {
+            let (x, y) = (n / other.cols, n % other.cols);
+            let mut entry = vec![T::Value::zero(); self.modulus];
+            for k in 0..self.cols {
+                let a = self.entry(x * self.cols + k);
+                let b = other.entry(k * other.cols + y);
+                for ((e, &a), &b) in entry.iter_mut().zip(a.iter()).zip(b.iter()) {
+                    *e += a * b;
+                }
+            }
+            entry
+        });
+
+        Ok(EvaluationMatrix {
+            values: entries.concat(),
+            rows: self.rows,
+            cols: other.cols,
+            modulus: self.modulus,
+            kind: self.kind,
+        })
+    }
+}
+
+impl<T: EvaluationDomain> Matrix<ModularArithmeticPolynomial<T>> {
+    /// Product of two matrices of polynomials, computed in point-value representation.
+    ///
+    /// Each entry of the operands is transformed once, the values are multiplied as a matrix product per root of the
+    /// modulus, and each entry of the result is interpolated once. This replaces the three transforms of each of the
+    /// rows * cols * inner polynomial products of the Mul operation by one transform per entry. It returns an error
+    /// if the polynomials don't all share the same modulus and kind, or if their coefficient type has no roots for
+    /// their modulus.
+    pub fn evaluation_product(&self, other: &Self) -> MatrixResult<ModularArithmeticPolynomial<T>> {
+        if self.cols != other.rows {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "Uncompatible matrix shapes for multiplication, {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            )));
+        }
+        let product =
+            (&EvaluationMatrix::from_matrix(self)? * &EvaluationMatrix::from_matrix(other)?)?;
+        product.to_matrix()
+    }
+}
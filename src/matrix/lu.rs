@@ -0,0 +1,262 @@
+use crate::algebra::Field;
+use crate::complex::Complex;
+use crate::matrix::*;
+
+/// Field elements which can be used as pivots of a numerical LU decomposition: floats and complex numbers
+pub trait Pivot: Field + MatrixInput {
+    /// Relative precision of the underlying real type
+    const EPSILON: f64;
+
+    /// Absolute value, used to choose the pivots
+    fn magnitude(self) -> f64;
+}
+
+impl Pivot for f32 {
+    const EPSILON: f64 = f32::EPSILON as f64;
+    fn magnitude(self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+impl Pivot for f64 {
+    const EPSILON: f64 = f64::EPSILON;
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Pivot for Complex<f32> {
+    const EPSILON: f64 = f32::EPSILON as f64;
+    fn magnitude(self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+impl Pivot for Complex<f64> {
+    const EPSILON: f64 = f64::EPSILON;
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+/// LU decomposition with partial pivoting of a square matrix A: P A = L U, where P is a permutation matrix, L is
+/// lower triangular with ones on its diagonal and U is upper triangular.
+///
+/// At each step, the pivot is the entry of largest magnitude in its column. A pivot which is not finite, or whose
+/// magnitude is below size * epsilon * max|A_k| for the column A_k of the matrix it comes from, is considered zero:
+/// the matrix is then singular, its determinant is zero, and solving with it fails instead of producing huge or
+/// infinite values. The tolerance scales with each column, so that badly scaled invertible matrices like
+/// diag(1e20, 1) are not singular.
+pub struct LuDecomposition<T: Pivot> {
+    /// L below the diagonal, and U on and above it
+    lu: Matrix<T>,
+    /// Row of A at each row of P A
+    pivots: Vec<usize>,
+    /// Whether the permutation is odd
+    odd: bool,
+    singular: bool,
+}
+
+impl<T: Pivot> LuDecomposition<T> {
+    fn new(matrix: &Matrix<T>) -> Result<Self, MatrixError> {
+        if matrix.rows != matrix.cols {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "LU decomposition of a non square matrix, {:?}",
+                matrix.shape()
+            )));
+        }
+        let size = matrix.rows;
+        let mut lu = matrix.clone();
+        let mut pivots: Vec<usize> = (0..size).collect();
+        let mut odd = false;
+        let mut singular = false;
+
+        // Rows are swapped, but the columns stay where they are
+        let tolerances: Vec<f64> = (0..size)
+            .map(|y| {
+                let scale = (0..size)
+                    .map(|x| matrix[(x, y)].magnitude())
+                    .fold(0.0, f64::max);
+                size as f64 * T::EPSILON * scale
+            })
+            .collect();
+
+        for k in 0..size {
+            let pivot_row = (k..size)
+                .max_by(|&i, &j| lu[(i, k)].magnitude().total_cmp(&lu[(j, k)].magnitude()))
+                .unwrap();
+            let magnitude = lu[(pivot_row, k)].magnitude();
+            if magnitude <= tolerances[k] || !magnitude.is_finite() {
+                // The column is already eliminated, up to rounding errors, or can't be
+                singular = true;
+                for x in k + 1..size {
+                    lu[(x, k)] = T::zero();
+                }
+                continue;
+            }
+            if pivot_row != k {
+                for y in 0..size {
+                    lu.arr.swap(k * size + y, pivot_row * size + y);
+                }
+                pivots.swap(k, pivot_row);
+                odd = !odd;
+            }
+
+            let pivot = lu[(k, k)];
+            for x in k + 1..size {
+                let factor = lu[(x, k)] / pivot;
+                lu[(x, k)] = factor;
+                for y in k + 1..size {
+                    let delta = factor * lu[(k, y)];
+                    lu[(x, y)] -= delta;
+                }
+            }
+        }
+
+        Ok(Self {
+            lu,
+            pivots,
+            odd,
+            singular,
+        })
+    }
+
+    /// Whether the decomposed matrix is singular
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Row of the decomposed matrix at each row of P A
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// The permutation matrix P
+    pub fn permutation(&self) -> Matrix<T> {
+        let size = self.pivots.len();
+        let mut ret = Matrix::new_empty(size, size, T::zero()).unwrap();
+        for (x, &row) in self.pivots.iter().enumerate() {
+            ret[(x, row)] = T::one();
+        }
+        ret
+    }
+
+    /// The lower triangular matrix L, with ones on its diagonal
+    pub fn lower(&self) -> Matrix<T> {
+        let mut ret = self.lu.clone();
+        for x in 0..ret.rows {
+            ret[(x, x)] = T::one();
+            for y in x + 1..ret.cols {
+                ret[(x, y)] = T::zero();
+            }
+        }
+        ret
+    }
+
+    /// The upper triangular matrix U
+    pub fn upper(&self) -> Matrix<T> {
+        let mut ret = self.lu.clone();
+        for x in 0..ret.rows {
+            for y in 0..x {
+                ret[(x, y)] = T::zero();
+            }
+        }
+        ret
+    }
+
+    /// Determinant of the decomposed matrix, which is exactly zero for a singular matrix
+    pub fn det(&self) -> T {
+        if self.singular {
+            return T::zero();
+        }
+        let mut ret = if self.odd { -T::one() } else { T::one() };
+        for k in 0..self.lu.rows {
+            ret *= self.lu[(k, k)];
+        }
+        ret
+    }
+
+    /// Solution X of A X = B, with one column of X for each column of B
+    pub fn solve(&self, b: &Matrix<T>) -> MatrixResult<T> {
+        let size = self.lu.rows;
+        if b.rows != size {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "Uncompatible matrix shapes for a linear solve, {:?} and {:?}",
+                self.lu.shape(),
+                b.shape()
+            )));
+        }
+        self.check_singular()?;
+
+        // Forward substitution with L, on the rows of B permuted as the ones of A, then back substitution with U
+        let mut x = Matrix::new_empty(size, b.cols, T::zero())?;
+        for (row, &pivot) in self.pivots.iter().enumerate() {
+            for y in 0..b.cols {
+                x[(row, y)] = b[(pivot, y)];
+            }
+        }
+        for y in 0..b.cols {
+            for row in 0..size {
+                let mut value = x[(row, y)];
+                for k in 0..row {
+                    value -= self.lu[(row, k)] * x[(k, y)];
+                }
+                x[(row, y)] = value;
+            }
+            for row in (0..size).rev() {
+                let mut value = x[(row, y)];
+                for k in row + 1..size {
+                    value -= self.lu[(row, k)] * x[(k, y)];
+                }
+                x[(row, y)] = value / self.lu[(row, row)];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Inverse of the decomposed matrix
+    pub fn inverse(&self) -> MatrixResult<T> {
+        self.check_singular()?;
+        self.solve(&Matrix::identity(self.lu.rows)?)
+    }
+
+    fn check_singular(&self) -> Result<(), MatrixError> {
+        if self.singular {
+            return Err(MatrixError::Singular("The matrix is singular".to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl<T: MatrixInput + Ring> Matrix<T> {
+    /// Creates the identity matrix of the given size
+    pub fn identity(size: usize) -> MatrixResult<T> {
+        let mut ret = Self::new_empty(size, size, T::zero())?;
+        for k in 0..size {
+            ret[(k, k)] = T::one();
+        }
+        Ok(ret)
+    }
+}
+
+impl<T: Pivot> Matrix<T> {
+    /// LU decomposition with partial pivoting, see LuDecomposition. The matrix must be square.
+    pub fn lu(&self) -> Result<LuDecomposition<T>, MatrixError> {
+        LuDecomposition::new(self)
+    }
+
+    /// Determinant of a square matrix, computed from its LU decomposition
+    pub fn det(&self) -> Result<T, MatrixError> {
+        Ok(self.lu()?.det())
+    }
+
+    /// Solution X of self X = b, for a square matrix. Returns MatrixError::Singular if the matrix is singular.
+    pub fn solve(&self, b: &Matrix<T>) -> MatrixResult<T> {
+        self.lu()?.solve(b)
+    }
+
+    /// Inverse of a square matrix. Returns MatrixError::Singular if the matrix is singular.
+    pub fn inverse(&self) -> MatrixResult<T> {
+        self.lu()?.inverse()
+    }
+}
@@ -3,17 +3,33 @@
 #[cfg(test)]
 mod test;
 
+mod evaluation;
+mod lu;
 mod mult;
+mod polynomial_matrix;
 
-use crate::complex::Number;
+pub use evaluation::EvaluationMatrix;
+pub use lu::{LuDecomposition, Pivot};
+pub use polynomial_matrix::PolynomialMatrix;
+
+use crate::algebra::{CommutativeRing, Ring};
 use crate::polynomial::{ModularArithmeticError, ModularArithmeticPolynomial};
 
 use std::iter::{Skip, StepBy};
 use std::ops::{Add, Index, IndexMut, Sub};
 
 /// We define the trait representing the minimum operations necessary to build a matrix out if it
-pub trait MatrixInput: Clone + std::fmt::Display {}
+pub trait MatrixInput: Clone + std::fmt::Display {
+    /// Checks that another entry can be in the same matrix as this one. Entries of most types always can, while
+    /// modular polynomials must share the same modulus and kind of ring.
+    fn check_compatible(&self, _other: &Self) -> Result<(), MatrixError> {
+        Ok(())
+    }
+}
 impl MatrixInput for f32 {}
+impl MatrixInput for f64 {}
+impl MatrixInput for i64 {}
+impl MatrixInput for i128 {}
 
 /// We define all our error types here
 #[derive(Debug)]
@@ -23,6 +39,7 @@ pub enum MatrixError {
     UncompatibleMatrixShapes(String),
     OutOfBoundsIndex(String),
     ModularArithmeticError(ModularArithmeticError),
+    Singular(String),
 }
 impl From<ModularArithmeticError> for MatrixError {
     fn from(e: ModularArithmeticError) -> Self {
@@ -31,7 +48,7 @@ impl From<ModularArithmeticError> for MatrixError {
 }
 type MatrixResult<T> = Result<Matrix<T>, MatrixError>;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Matrix<T> {
     arr: Vec<T>,
     cols: usize,
@@ -83,6 +100,8 @@ impl<T: MatrixInput> Matrix<T> {
     }
 
     /// Creates a new matrix with the provided data (which should spans columns before rows)
+    ///
+    /// All the entries must be compatible with each other, see MatrixInput::check_compatible.
     pub fn new(arr: Vec<T>, x: usize, y: usize) -> MatrixResult<T> {
         Self::check_zero_dimension(x, y)?;
         if arr.len() != x * y {
@@ -93,11 +112,30 @@ impl<T: MatrixInput> Matrix<T> {
             )));
         }
 
-        return Ok(Self {
+        let ret = Self {
             cols: y,
             rows: x,
             arr: arr,
-        });
+        };
+        ret.check_entries()?;
+        return Ok(ret);
+    }
+
+    /// Checks that all the entries are compatible with each other, see MatrixInput::check_compatible.
+    ///
+    /// Entries can be replaced through IndexMut without any check, so the operations check them again.
+    pub fn check_entries(&self) -> Result<(), MatrixError> {
+        for entry in self.arr.iter().skip(1) {
+            self.arr[0].check_compatible(entry)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that all the entries of both matrices are compatible with each other
+    fn check_entries_with(&self, other: &Matrix<T>) -> Result<(), MatrixError> {
+        self.check_entries()?;
+        other.check_entries()?;
+        self.arr[0].check_compatible(&other.arr[0])
     }
 
     #[inline]
@@ -181,8 +219,8 @@ impl<T: MatrixInput> IndexMut<(usize, usize)> for Matrix<T> {
     }
 }
 
-/// Add operation for any input that is a Number (in particular: has Copy and Add by value)
-impl<'a, T: MatrixInput + Number> Add for &'a Matrix<T> {
+/// Add operation for any input that is a Ring (in particular: has Copy and Add by value)
+impl<'a, T: MatrixInput + Ring> Add for &'a Matrix<T> {
     type Output = MatrixResult<T>;
 
     fn add(self, other: &'a Matrix<T>) -> MatrixResult<T> {
@@ -206,8 +244,8 @@ impl<'a, T: MatrixInput + Number> Add for &'a Matrix<T> {
     }
 }
 
-/// Sub operation for any input that is a Number (in particular: has Copy and Add by value)
-impl<'a, T: MatrixInput + Number> Sub for &'a Matrix<T> {
+/// Sub operation for any input that is a Ring (in particular: has Copy and Add by value)
+impl<'a, T: MatrixInput + Ring> Sub for &'a Matrix<T> {
     type Output = MatrixResult<T>;
 
     fn sub(self, other: &'a Matrix<T>) -> MatrixResult<T> {
@@ -233,7 +271,7 @@ impl<'a, T: MatrixInput + Number> Sub for &'a Matrix<T> {
 
 /// Add operation for Polynomials, which don't have the Copy trait, and thus add by reference
 /// In addition, this allows catching any error coming from the modular Arithmetic module
-impl<'a, T: Number> Add for &'a Matrix<ModularArithmeticPolynomial<T>> {
+impl<'a, T: CommutativeRing> Add for &'a Matrix<ModularArithmeticPolynomial<T>> {
     type Output = MatrixResult<ModularArithmeticPolynomial<T>>;
 
     fn add(
@@ -248,6 +286,8 @@ impl<'a, T: Number> Add for &'a Matrix<ModularArithmeticPolynomial<T>> {
             )));
         }
 
+        self.check_entries_with(other)?;
+
         let mut vec = Vec::<ModularArithmeticPolynomial<T>>::with_capacity(self.len());
         for i in 0..self.len() {
             vec.push((&self.arr[i] + &other.arr[i])?);
@@ -262,7 +302,7 @@ impl<'a, T: Number> Add for &'a Matrix<ModularArithmeticPolynomial<T>> {
 
 /// Add operation for Polynomials, which don't have the Copy trait, and thus add by reference
 /// In addition, this allows catching any error coming from the modular Arithmetic module
-impl<'a, T: Number> Sub for &'a Matrix<ModularArithmeticPolynomial<T>> {
+impl<'a, T: CommutativeRing> Sub for &'a Matrix<ModularArithmeticPolynomial<T>> {
     type Output = MatrixResult<ModularArithmeticPolynomial<T>>;
 
     fn sub(
@@ -277,6 +317,8 @@ impl<'a, T: Number> Sub for &'a Matrix<ModularArithmeticPolynomial<T>> {
             )));
         }
 
+        self.check_entries_with(other)?;
+
         let mut vec = Vec::<ModularArithmeticPolynomial<T>>::with_capacity(self.len());
         for i in 0..self.len() {
             vec.push((&self.arr[i] - &other.arr[i])?);
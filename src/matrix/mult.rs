@@ -1,11 +1,12 @@
-use crate::complex;
-use crate::complex::Number;
+use crate::algebra::Ring;
 use crate::matrix::*;
+use crate::parallel;
+use crate::polynomial::convolution::FastConvolution;
 
 use std::ops::Mul;
 
-/// Mul operation for any input that is a Number (in particular: has Copy and Mul by value)
-impl<'a, T: MatrixInput + Number> Mul for &'a Matrix<T> {
+/// Mul operation for any input that is a Ring (in particular: has Copy and Mul by value)
+impl<'a, T: MatrixInput + Ring> Mul for &'a Matrix<T> {
     type Output = MatrixResult<T>;
 
     fn mul(self, other: &'a Matrix<T>) -> MatrixResult<T> {
@@ -18,28 +19,30 @@ impl<'a, T: MatrixInput + Number> Mul for &'a Matrix<T> {
         }
 
         let other_transposed = other.clone_transposed();
-        let mut coefs = Vec::<T>::with_capacity(self.rows * other.cols);
 
-        for x in 0..self.rows {
-            for y in 0..other.cols {
-                let mut coef = T::from(0.0);
-                for (a, b) in self.row(x)?.zip(other_transposed.row(y)?) {
-                    coef += *a * *b;
-                }
-                coefs.push(coef);
+        let coefs = parallel::map_indices(self.rows * other.cols, |n| {
+            let (x, y) = (n / other.cols, n % other.cols);
+            let mut coef = T::zero();
+            for (a, b) in self.row(x)?.zip(other_transposed.row(y)?) {
+                coef += *a * *b;
             }
-        }
+            Ok(coef)
+        });
 
-        Matrix::<T>::new(coefs, self.rows, other.cols)
+        Matrix::<T>::new(
+            coefs.into_iter().collect::<Result<_, MatrixError>>()?,
+            self.rows,
+            other.cols,
+        )
     }
 }
 
 /// Mul operation for Polynomials, which don't have the Copy trait, and thus add by reference
-/// In addition, this allows catching any error coming from the modular Arithmetic module
+/// In addition, this allows catching any error coming from the modular Arithmetic module, such as entries with
+/// different moduli
 impl<'a, T> Mul for &'a Matrix<ModularArithmeticPolynomial<T>>
 where
-    T: Number + From<complex::Complex<f64>>,
-    complex::Complex<f64>: From<T>,
+    T: FastConvolution,
 {
     type Output = MatrixResult<ModularArithmeticPolynomial<T>>;
 
@@ -55,22 +58,27 @@ where
             )));
         }
 
+        // All the entries of both matrices must share the same modulus, so that the accumulation below can't fail
+        self.check_entries_with(other)?;
+
         let other_transposed = other.clone_transposed();
 
         let modulus = self[(0, 0)].modulus();
-        let mut coefs =
-            Vec::<ModularArithmeticPolynomial<T>>::with_capacity(self.rows * other.cols);
+        let kind = self[(0, 0)].kind();
 
-        for x in 0..self.rows {
-            for y in 0..other.cols {
-                let mut coef = ModularArithmeticPolynomial::<T>::new_zero(modulus);
-                for (a, b) in self.row(x)?.zip(other_transposed.row(y)?) {
-                    coef += &(a * b)?;
-                }
-                coefs.push(coef);
+        let coefs = parallel::map_indices(self.rows * other.cols, |n| {
+            let (x, y) = (n / other.cols, n % other.cols);
+            let mut coef = ModularArithmeticPolynomial::<T>::new_zero_with_kind(modulus, kind);
+            for (a, b) in self.row(x)?.zip(other_transposed.row(y)?) {
+                coef.checked_add_assign(&(a * b)?)?;
             }
-        }
+            Ok(coef)
+        });
 
-        Matrix::<ModularArithmeticPolynomial<T>>::new(coefs, self.rows, other.cols)
+        Matrix::<ModularArithmeticPolynomial<T>>::new(
+            coefs.into_iter().collect::<Result<_, MatrixError>>()?,
+            self.rows,
+            other.cols,
+        )
     }
 }
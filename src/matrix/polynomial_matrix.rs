@@ -0,0 +1,291 @@
+use crate::matrix::*;
+use crate::parallel;
+use crate::polynomial::convolution::{convolution, linear_convolution, FastConvolution};
+use crate::polynomial::{check_ring, Polynomial, RingKind};
+
+use std::ops::Mul;
+
+/// Matrix of modular polynomials which all share the same modulus and kind of ring, with all their coefficients
+/// stored in a single buffer.
+///
+/// Unlike a Matrix of ModularArithmeticPolynomial, where each entry owns its own allocation, the coefficients of
+/// the entries are contiguous, entries spanning columns before rows. Indexing gives a view on the coefficients of an
+/// entry, whose size is always the modulus.
+#[derive(Clone, Debug)]
+pub struct PolynomialMatrix<T: CommutativeRing> {
+    coefs: Vec<T>,
+    rows: usize,
+    cols: usize,
+    modulus: usize,
+    kind: RingKind,
+}
+
+type PolynomialMatrixResult<T> = Result<PolynomialMatrix<T>, MatrixError>;
+
+/// Implement the Display trait
+impl<T: CommutativeRing> std::fmt::Display for PolynomialMatrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ret = "[".to_string();
+
+        for x in 0..self.rows {
+            ret.push('[');
+            for y in 0..self.cols {
+                ret.push_str(&Polynomial::new(&self[(x, y)]).to_string());
+                if y != self.cols - 1 {
+                    ret.push_str(", ");
+                }
+            }
+            ret.push(']');
+            if x != self.rows - 1 {
+                ret.push(',');
+                ret.push('\n');
+            }
+        }
+
+        ret.push(']');
+        f.write_str(&ret)
+    }
+}
+
+impl<T: CommutativeRing> PolynomialMatrix<T> {
+    /// Creates a new matrix filled with zero polynomials. The modulus counts as a dimension, and must be non-zero.
+    pub fn new_zero(
+        rows: usize,
+        cols: usize,
+        modulus: usize,
+        kind: RingKind,
+    ) -> PolynomialMatrixResult<T> {
+        if rows == 0 || cols == 0 || modulus == 0 {
+            return Err(MatrixError::ZeroDimension(
+                "All dimensions must be non-zero".to_string(),
+            ));
+        }
+        Ok(Self {
+            coefs: vec![T::zero(); rows * cols * modulus],
+            rows,
+            cols,
+            modulus,
+            kind,
+        })
+    }
+
+    /// Creates a new matrix from a matrix of polynomials, which must all share the same modulus and kind of ring
+    pub fn from_matrix(
+        matrix: &Matrix<ModularArithmeticPolynomial<T>>,
+    ) -> PolynomialMatrixResult<T> {
+        matrix.check_entries()?;
+        let first = &matrix.arr[0];
+        let mut ret = Self::new_zero(matrix.rows, matrix.cols, first.modulus(), first.kind())?;
+        for (n, entry) in matrix.arr.iter().enumerate() {
+            ret.entry_mut(n).copy_from_slice(entry.coefs());
+        }
+        Ok(ret)
+    }
+
+    /// Converts to a matrix of polynomials
+    pub fn to_matrix(&self) -> MatrixResult<ModularArithmeticPolynomial<T>> {
+        let entries = (0..self.len())
+            .map(|n| {
+                ModularArithmeticPolynomial::new_with_kind(
+                    &Polynomial::new(self.entry(n)),
+                    self.modulus,
+                    self.kind,
+                )
+            })
+            .collect();
+        Matrix::new(entries, self.rows, self.cols)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Modulus of all the entries
+    pub fn modulus(&self) -> usize {
+        self.modulus
+    }
+
+    /// Kind of ring of all the entries
+    pub fn kind(&self) -> RingKind {
+        self.kind
+    }
+
+    /// Public helper to help detect fraudulant indexing (allows error reporting)
+    pub fn check_idx(&self, x: usize, y: usize) -> Result<(), MatrixError> {
+        if x >= self.rows {
+            return Err(MatrixError::OutOfBoundsIndex(format!(
+                "x index too high: {} for size {}",
+                x, self.rows
+            )));
+        } else if y >= self.cols {
+            return Err(MatrixError::OutOfBoundsIndex(format!(
+                "y index too high: {} for size {}",
+                y, self.cols
+            )));
+        }
+        Ok(())
+    }
+
+    /// Copy of an entry, as a modular polynomial
+    pub fn polynomial(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> Result<ModularArithmeticPolynomial<T>, MatrixError> {
+        self.check_idx(x, y)?;
+        Ok(ModularArithmeticPolynomial::new_with_kind(
+            &Polynomial::new(&self[(x, y)]),
+            self.modulus,
+            self.kind,
+        ))
+    }
+
+    /// Replaces an entry, which must have the modulus and kind of ring of the matrix
+    pub fn set_polynomial(
+        &mut self,
+        x: usize,
+        y: usize,
+        poly: &ModularArithmeticPolynomial<T>,
+    ) -> Result<(), MatrixError> {
+        self.check_idx(x, y)?;
+        check_ring((self.modulus, self.kind), (poly.modulus(), poly.kind()))?;
+        self[(x, y)].copy_from_slice(poly.coefs());
+        Ok(())
+    }
+
+    /// Coefficients of the entry at index n, spanning columns before rows
+    fn entry(&self, n: usize) -> &[T] {
+        &self.coefs[n * self.modulus..(n + 1) * self.modulus]
+    }
+
+    fn entry_mut(&mut self, n: usize) -> &mut [T] {
+        &mut self.coefs[n * self.modulus..(n + 1) * self.modulus]
+    }
+
+    /// Check the shape and the ring of another matrix against this one, for an addition or a subtraction
+    fn check_same_shape(&self, other: &PolynomialMatrix<T>) -> Result<(), MatrixError> {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "Uncompatible matrix shapes for addition, {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            )));
+        }
+        check_ring((self.modulus, self.kind), (other.modulus, other.kind))?;
+        Ok(())
+    }
+
+    /// Coefficient by coefficient combination of two matrices of the same shape and ring
+    fn zip_with(
+        &self,
+        other: &PolynomialMatrix<T>,
+        f: impl Fn(T, T) -> T,
+    ) -> PolynomialMatrixResult<T> {
+        self.check_same_shape(other)?;
+        Ok(Self {
+            coefs: self
+                .coefs
+                .iter()
+                .zip(other.coefs.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            ..*self
+        })
+    }
+}
+
+impl<T: CommutativeRing> Index<(usize, usize)> for PolynomialMatrix<T> {
+    type Output = [T];
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.entry(index.0 * self.cols + index.1)
+    }
+}
+impl<T: CommutativeRing> IndexMut<(usize, usize)> for PolynomialMatrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let n = index.0 * self.cols + index.1;
+        self.entry_mut(n)
+    }
+}
+
+/// Add operation, coefficient by coefficient on the whole buffer
+impl<'a, T: CommutativeRing> Add for &'a PolynomialMatrix<T> {
+    type Output = PolynomialMatrixResult<T>;
+
+    fn add(self, other: &'a PolynomialMatrix<T>) -> PolynomialMatrixResult<T> {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+/// Sub operation, coefficient by coefficient on the whole buffer
+impl<'a, T: CommutativeRing> Sub for &'a PolynomialMatrix<T> {
+    type Output = PolynomialMatrixResult<T>;
+
+    fn sub(self, other: &'a PolynomialMatrix<T>) -> PolynomialMatrixResult<T> {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+/// Mul operation, with the polynomial products computed on views of the buffers.
+///
+/// In a negacyclic ring, the full products of each output entry are summed first, and folded back only once.
+impl<'a, T: FastConvolution> Mul for &'a PolynomialMatrix<T> {
+    type Output = PolynomialMatrixResult<T>;
+
+    fn mul(self, other: &'a PolynomialMatrix<T>) -> PolynomialMatrixResult<T> {
+        if self.cols != other.rows {
+            return Err(MatrixError::UncompatibleMatrixShapes(format!(
+                "Uncompatible matrix shapes for multiplication, {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            )));
+        }
+        check_ring((self.modulus, self.kind), (other.modulus, other.kind))?;
+
+        let modulus = self.modulus;
+        let entries = parallel::map_indices(self.rows * other.cols, |n| {
+            let (x, y) = (n / other.cols, n % other.cols);
+            match self.kind {
+                RingKind::Cyclic => {
+                    let mut entry = vec![T::zero(); modulus];
+                    for k in 0..self.cols {
+                        let product = convolution(&self[(x, k)], &other[(k, y)]);
+                        for (e, p) in entry.iter_mut().zip(product) {
+                            *e += p;
+                        }
+                    }
+                    entry
+                }
+                RingKind::Negacyclic => {
+                    let mut entry = vec![T::zero(); 2 * modulus - 1];
+                    for k in 0..self.cols {
+                        let product = linear_convolution(&self[(x, k)], &other[(k, y)]);
+                        for (e, p) in entry.iter_mut().zip(product) {
+                            *e += p;
+                        }
+                    }
+                    // x^modulus = -1
+                    let high = entry.split_off(modulus);
+                    for (e, h) in entry.iter_mut().zip(high) {
+                        *e -= h;
+                    }
+                    entry
+                }
+            }
+        });
+
+        Ok(PolynomialMatrix {
+            coefs: entries.concat(),
+            rows: self.rows,
+            cols: other.cols,
+            modulus,
+            kind: self.kind,
+        })
+    }
+}
@@ -313,3 +313,578 @@ fn matrix_product_polynomials_error() {
         _ => panic!("Wrong error type"),
     }
 }
+
+#[test]
+fn mult_prime_field() {
+    type F = crate::prime_field::Zp<7>;
+    let a = matrix::Matrix::new(
+        [1, 2, 3, 4, 5, 6].iter().map(|&x| F::new(x)).collect(),
+        2,
+        3,
+    )
+    .unwrap();
+    let b = matrix::Matrix::new(
+        [6, 5, 4, 3, 2, 1].iter().map(|&x| F::new(x)).collect(),
+        3,
+        2,
+    )
+    .unwrap();
+
+    // Same product as over the integers: [[20, 14], [56, 41]]
+    let c = (&a * &b).unwrap();
+    assert_eq!(c[(0, 0)], F::new(20));
+    assert_eq!(c[(0, 1)], F::new(14));
+    assert_eq!(c[(1, 0)], F::new(56));
+    assert_eq!(c[(1, 1)], F::new(41));
+}
+
+#[test]
+fn mult_integers() {
+    let a = matrix::Matrix::<i64>::new(vec![1, 2, 3, 4], 2, 2).unwrap();
+    let b = matrix::Matrix::<i128>::new(vec![i64::MAX as i128, 0, 0, 2], 2, 2).unwrap();
+
+    let c = (&a * &a).unwrap();
+    assert_eq!(c[(0, 0)], 7);
+    assert_eq!(c[(0, 1)], 10);
+    assert_eq!(c[(1, 0)], 15);
+    assert_eq!(c[(1, 1)], 22);
+
+    let d = (&b * &b).unwrap();
+    assert_eq!(d[(0, 0)], (i64::MAX as i128) * (i64::MAX as i128));
+    assert_eq!(d[(1, 1)], 4);
+}
+
+#[test]
+fn matrix_product_deterministic() {
+    // With the parallel feature, entries are computed by several threads: each one must still be computed with the
+    // sequential order of operations
+    let (rows, inner, cols) = (20, 30, 25);
+    let value = |n: usize| ((n as f64) * 0.37).sin();
+    let a = matrix::Matrix::new((0..rows * inner).map(value).collect(), rows, inner).unwrap();
+    let b = matrix::Matrix::new(
+        (0..inner * cols).map(|n| value(n + 1000)).collect(),
+        inner,
+        cols,
+    )
+    .unwrap();
+
+    let c = (&a * &b).unwrap();
+    for x in 0..rows {
+        for y in 0..cols {
+            let mut expected = 0.0;
+            for k in 0..inner {
+                expected += a[(x, k)] * b[(k, y)];
+            }
+            assert_eq!(c[(x, y)], expected);
+        }
+    }
+}
+
+#[test]
+fn matrix_product_polynomials_deterministic() {
+    let size = 6;
+    let polynomial = |n: usize| {
+        let coefs: Vec<complex::Complex<f64>> = (0..40)
+            .map(|k| complex::Complex::new(((n * 40 + k) as f64).sin(), ((n + k) as f64).cos()))
+            .collect();
+        ModularArithmeticPolynomial::new(&Polynomial::new(&coefs), 40)
+    };
+    let a = matrix::Matrix::new((0..size * size).map(polynomial).collect(), size, size).unwrap();
+    let b = matrix::Matrix::new(
+        (0..size * size).map(|n| polynomial(n + 100)).collect(),
+        size,
+        size,
+    )
+    .unwrap();
+
+    let c = (&a * &b).unwrap();
+    for x in 0..size {
+        for y in 0..size {
+            let mut expected = ModularArithmeticPolynomial::new_zero(40);
+            for k in 0..size {
+                expected += &(&a[(x, k)] * &b[(k, y)]).unwrap();
+            }
+            for n in 0..40 {
+                assert_eq!(c[(x, y)].coef(n).unwrap(), expected.coef(n).unwrap());
+            }
+        }
+    }
+}
+
+use crate::matrix::EvaluationMatrix;
+use crate::polynomial::{ModularArithmeticError, RingKind};
+use crate::prime_field::{Zp, P_998244353};
+
+fn zp_polynomial_matrix(
+    rows: usize,
+    cols: usize,
+    modulus: usize,
+    kind: RingKind,
+    seed: u64,
+) -> matrix::Matrix<ModularArithmeticPolynomial<Zp<P_998244353>>> {
+    let entries = (0..rows * cols)
+        .map(|n| {
+            let coefs: Vec<Zp<P_998244353>> = (0..modulus as u64)
+                .map(|k| Zp::new((n as u64 * 7919 + k * 104729 + seed * 15485863) % 1000003))
+                .collect();
+            ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&coefs), modulus, kind)
+        })
+        .collect();
+    matrix::Matrix::new(entries, rows, cols).unwrap()
+}
+
+#[test]
+fn evaluation_product_prime_field() {
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        let a = zp_polynomial_matrix(3, 4, 32, kind, 1);
+        let b = zp_polynomial_matrix(4, 2, 32, kind, 2);
+
+        let product = a.evaluation_product(&b).unwrap();
+        let expected = (&a * &b).unwrap();
+        assert_eq!(product.shape(), (3, 2));
+        for x in 0..3 {
+            for y in 0..2 {
+                assert_eq!(product[(x, y)].kind(), kind);
+                for n in 0..32 {
+                    assert_eq!(
+                        product[(x, y)].coef(n).unwrap(),
+                        expected[(x, y)].coef(n).unwrap()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluation_product_complex() {
+    let polynomial = |n: usize, kind: RingKind| {
+        let coefs: Vec<complex::Complex<f64>> = (0..12)
+            .map(|k| complex::Complex::new(((n * 12 + k) as f64).sin(), ((n + k) as f64).cos()))
+            .collect();
+        ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&coefs), 12, kind)
+    };
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        let a = matrix::Matrix::new((0..6).map(|n| polynomial(n, kind)).collect(), 2, 3).unwrap();
+        let b = matrix::Matrix::new((6..12).map(|n| polynomial(n, kind)).collect(), 3, 2).unwrap();
+
+        let product = a.evaluation_product(&b).unwrap();
+        let expected = (&a * &b).unwrap();
+        for x in 0..2 {
+            for y in 0..2 {
+                for n in 0..12 {
+                    let difference =
+                        product[(x, y)].coef(n).unwrap() - expected[(x, y)].coef(n).unwrap();
+                    assert!(difference.abs() < 1e-9);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluation_matrix_chain() {
+    let a = zp_polynomial_matrix(2, 2, 16, RingKind::Negacyclic, 3);
+    let b = zp_polynomial_matrix(2, 3, 16, RingKind::Negacyclic, 4);
+    let c = zp_polynomial_matrix(3, 1, 16, RingKind::Negacyclic, 5);
+
+    let a_values = EvaluationMatrix::from_matrix(&a).unwrap();
+    let b_values = EvaluationMatrix::from_matrix(&b).unwrap();
+    let c_values = EvaluationMatrix::from_matrix(&c).unwrap();
+    assert_eq!(b_values.shape(), (2, 3));
+    assert_eq!(b_values.modulus(), 16);
+    assert_eq!(b_values.kind(), RingKind::Negacyclic);
+
+    // The conversion is lossless in a prime field
+    let back = a_values.to_matrix().unwrap();
+    for x in 0..2 {
+        for y in 0..2 {
+            for n in 0..16 {
+                assert_eq!(back[(x, y)].coef(n).unwrap(), a[(x, y)].coef(n).unwrap());
+            }
+        }
+    }
+
+    let product = (&(&a_values * &b_values).unwrap() * &c_values)
+        .unwrap()
+        .to_matrix()
+        .unwrap();
+    let expected = (&(&a * &b).unwrap() * &c).unwrap();
+    assert_eq!(product.shape(), (2, 1));
+    for x in 0..2 {
+        for n in 0..16 {
+            assert_eq!(
+                product[(x, 0)].coef(n).unwrap(),
+                expected[(x, 0)].coef(n).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn evaluation_matrix_errors() {
+    let a = zp_polynomial_matrix(2, 2, 16, RingKind::Cyclic, 1);
+    let b = zp_polynomial_matrix(2, 2, 8, RingKind::Cyclic, 2);
+    let c = zp_polynomial_matrix(2, 2, 16, RingKind::Negacyclic, 3);
+
+    match a.evaluation_product(&b) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match a.evaluation_product(&c) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::RingKindMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+
+    // Entries of a single matrix must share the same modulus
+    let mut d = zp_polynomial_matrix(2, 2, 16, RingKind::Cyclic, 4);
+    d[(1, 1)] = ModularArithmeticPolynomial::new_zero(8);
+    match EvaluationMatrix::from_matrix(&d) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+
+    // The ntt needs a power of 2 modulus
+    let e = zp_polynomial_matrix(2, 2, 12, RingKind::Cyclic, 5);
+    match e.evaluation_product(&e) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::NoEvaluationDomain(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+use crate::matrix::PolynomialMatrix;
+
+/// Checks that a contiguous polynomial matrix has the same entries as a matrix of polynomials
+fn assert_same_entries(
+    contiguous: &PolynomialMatrix<Zp<P_998244353>>,
+    expected: &matrix::Matrix<ModularArithmeticPolynomial<Zp<P_998244353>>>,
+) {
+    assert_eq!(contiguous.shape(), expected.shape());
+    let (rows, cols) = expected.shape();
+    for x in 0..rows {
+        for y in 0..cols {
+            for n in 0..contiguous.modulus() {
+                assert_eq!(contiguous[(x, y)][n], expected[(x, y)].coef(n).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn polynomial_matrix_conversions() {
+    let a = zp_polynomial_matrix(3, 2, 5, RingKind::Negacyclic, 1);
+    let mut contiguous = PolynomialMatrix::from_matrix(&a).unwrap();
+    assert_eq!(contiguous.modulus(), 5);
+    assert_eq!(contiguous.kind(), RingKind::Negacyclic);
+    assert_same_entries(&contiguous, &a);
+
+    let back = contiguous.to_matrix().unwrap();
+    for x in 0..3 {
+        for y in 0..2 {
+            assert_eq!(back[(x, y)].kind(), RingKind::Negacyclic);
+            assert_eq!(back[(x, y)].coef(4).unwrap(), a[(x, y)].coef(4).unwrap());
+        }
+    }
+
+    // Entries are views on the buffer
+    contiguous[(2, 1)][3] = Zp::new(42);
+    assert_eq!(
+        contiguous.polynomial(2, 1).unwrap().coef(3).unwrap(),
+        Zp::new(42)
+    );
+    let zero = ModularArithmeticPolynomial::new_zero_with_kind(5, RingKind::Negacyclic);
+    contiguous.set_polynomial(0, 0, &zero).unwrap();
+    assert!(contiguous[(0, 0)].iter().all(|&c| c == Zp::new(0)));
+}
+
+#[test]
+fn polynomial_matrix_operations() {
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        // Large enough modulus for the fast convolutions
+        let a = zp_polynomial_matrix(3, 4, 200, kind, 1);
+        let b = zp_polynomial_matrix(4, 2, 200, kind, 2);
+        let c = zp_polynomial_matrix(3, 4, 200, kind, 3);
+        let a_contiguous = PolynomialMatrix::from_matrix(&a).unwrap();
+        let b_contiguous = PolynomialMatrix::from_matrix(&b).unwrap();
+        let c_contiguous = PolynomialMatrix::from_matrix(&c).unwrap();
+
+        assert_same_entries(
+            &(&a_contiguous * &b_contiguous).unwrap(),
+            &(&a * &b).unwrap(),
+        );
+        assert_same_entries(
+            &(&a_contiguous + &c_contiguous).unwrap(),
+            &(&a + &c).unwrap(),
+        );
+        assert_same_entries(
+            &(&a_contiguous - &c_contiguous).unwrap(),
+            &(&a - &c).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn polynomial_matrix_errors() {
+    match PolynomialMatrix::<f64>::new_zero(2, 2, 0, RingKind::Cyclic) {
+        Err(matrix::MatrixError::ZeroDimension(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+
+    // Entries must all share the same modulus
+    let mut a = zp_polynomial_matrix(2, 2, 4, RingKind::Cyclic, 1);
+    a[(0, 1)] = ModularArithmeticPolynomial::new_zero(3);
+    match PolynomialMatrix::from_matrix(&a) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+
+    let mut b = PolynomialMatrix::<f64>::new_zero(2, 2, 4, RingKind::Cyclic).unwrap();
+    let c = PolynomialMatrix::<f64>::new_zero(2, 2, 4, RingKind::Negacyclic).unwrap();
+    let d = PolynomialMatrix::<f64>::new_zero(3, 2, 4, RingKind::Cyclic).unwrap();
+    match b.set_polynomial(0, 0, &ModularArithmeticPolynomial::new_zero(5)) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match b.polynomial(2, 0) {
+        Err(matrix::MatrixError::OutOfBoundsIndex(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &b * &c {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::RingKindMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &b + &d {
+        Err(matrix::MatrixError::UncompatibleMatrixShapes(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &b * &d {
+        Err(matrix::MatrixError::UncompatibleMatrixShapes(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+#[test]
+fn polynomial_matrix_moduli_validation() {
+    let poly = |modulus: usize| {
+        ModularArithmeticPolynomial::new(&Polynomial::new(&[1.0, 2.0, 3.0]), modulus)
+    };
+
+    // At construction
+    match matrix::Matrix::new(vec![poly(3), poly(3), poly(4), poly(3)], 2, 2) {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+
+    // Entries replaced afterwards are caught by the operations, whatever their position
+    let a = matrix::Matrix::new(vec![poly(3), poly(3), poly(3), poly(3)], 2, 2).unwrap();
+    let mut b = matrix::Matrix::new(vec![poly(3), poly(3), poly(3), poly(3)], 2, 2).unwrap();
+    b[(1, 1)] = poly(4);
+    assert!(b.check_entries().is_err());
+    for result in [&a * &b, &b * &a, &a + &b, &a - &b, a.evaluation_product(&b)] {
+        match result {
+            Err(matrix::MatrixError::ModularArithmeticError(
+                ModularArithmeticError::ModulusMismatched(_),
+            )) => (),
+            _ => panic!("Wrong error type"),
+        }
+    }
+
+    // Both operands must share the same ring as well
+    let c = matrix::Matrix::new(vec![poly(4), poly(4), poly(4), poly(4)], 2, 2).unwrap();
+    let negacyclic = ModularArithmeticPolynomial::new_negacyclic(&Polynomial::new(&[1.0]), 3);
+    let d = matrix::Matrix::new(vec![negacyclic.clone(), negacyclic], 2, 1).unwrap();
+    match &a * &c {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::ModulusMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &a * &d {
+        Err(matrix::MatrixError::ModularArithmeticError(
+            ModularArithmeticError::RingKindMismatched(_),
+        )) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+#[test]
+fn lu_decomposition() {
+    // The first pivot can't be taken on the diagonal
+    let a = matrix::Matrix::<f64>::new(vec![0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 4.0, -2.0, 3.0], 3, 3)
+        .unwrap();
+    let lu = a.lu().unwrap();
+    assert!(!lu.is_singular());
+
+    let lower = lu.lower();
+    let upper = lu.upper();
+    let product = (&lu.permutation() * &a).unwrap();
+    let expected = (&lower * &upper).unwrap();
+    for x in 0..3 {
+        assert_eq!(lower[(x, x)], 1.0);
+        for y in 0..3 {
+            if y > x {
+                assert_eq!(lower[(x, y)], 0.0);
+            }
+            if y < x {
+                assert_eq!(upper[(x, y)], 0.0);
+                // Partial pivoting keeps the factors of L at most 1
+                assert!(lower[(x, y)].abs() <= 1.0);
+            }
+            assert!((product[(x, y)] - expected[(x, y)]).abs() < 1e-12);
+        }
+    }
+    assert_eq!(lu.pivots()[0], 2);
+    assert!((a.det().unwrap() + 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn lu_solve_inverse() {
+    let a = matrix::Matrix::<f64>::new(vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0], 3, 3)
+        .unwrap();
+    let b = matrix::Matrix::<f64>::new(vec![8.0, -11.0, -3.0], 3, 1).unwrap();
+
+    let x = a.solve(&b).unwrap();
+    for (n, expected) in [2.0, 3.0, -1.0].iter().enumerate() {
+        assert!((x[(n, 0)] - expected).abs() < 1e-12);
+    }
+
+    let inverse = a.inverse().unwrap();
+    let identity = (&a * &inverse).unwrap();
+    for x in 0..3 {
+        for y in 0..3 {
+            let expected = if x == y { 1.0 } else { 0.0 };
+            assert!((identity[(x, y)] - expected).abs() < 1e-12);
+        }
+    }
+
+    // Single precision
+    let a_f32 = matrix::Matrix::<f32>::new(vec![4.0, 3.0, 6.0, 3.0], 2, 2).unwrap();
+    assert!((a_f32.det().unwrap() + 6.0).abs() < 1e-5);
+    let x_f32 = a_f32
+        .solve(&matrix::Matrix::new(vec![10.0, 12.0], 2, 1).unwrap())
+        .unwrap();
+    assert!((x_f32[(0, 0)] - 1.0).abs() < 1e-5);
+    assert!((x_f32[(1, 0)] - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn lu_complex() {
+    let i = complex::I_F64;
+    let one = complex::Complex::<f64>::from(1.0);
+    let two = complex::Complex::<f64>::from(2.0);
+    // det = (1 + i) * 2 - i * 1 = 2 + i
+    let a = matrix::Matrix::new(vec![one + i, i, one, two], 2, 2).unwrap();
+    assert!((a.det().unwrap() - complex::Complex::new(2.0, 1.0)).abs() < 1e-12);
+
+    let inverse = a.inverse().unwrap();
+    let identity = (&inverse * &a).unwrap();
+    for x in 0..2 {
+        for y in 0..2 {
+            let expected = if x == y {
+                one
+            } else {
+                complex::Complex::from(0.0)
+            };
+            assert!((identity[(x, y)] - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn lu_singular() {
+    // The second row is twice the first one, and the elimination is exact
+    let a = matrix::Matrix::<f64>::new(vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0], 3, 3)
+        .unwrap();
+    let lu = a.lu().unwrap();
+    assert!(lu.is_singular());
+    assert_eq!(a.det().unwrap(), 0.0);
+
+    // The decomposition still holds
+    let product = (&lu.permutation() * &a).unwrap();
+    let expected = (&lu.lower() * &lu.upper()).unwrap();
+    for x in 0..3 {
+        for y in 0..3 {
+            assert!((product[(x, y)] - expected[(x, y)]).abs() < 1e-12);
+        }
+    }
+
+    let b = matrix::Matrix::<f64>::new(vec![1.0, 2.0, 3.0], 3, 1).unwrap();
+    match a.solve(&b) {
+        Err(matrix::MatrixError::Singular(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    let zero = matrix::Matrix::<f64>::new_empty(2, 2, 0.0).unwrap();
+    match zero.inverse() {
+        Err(matrix::MatrixError::Singular(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+#[test]
+fn lu_badly_scaled() {
+    // Invertible matrices whose entries have very different magnitudes are not singular
+    let a = matrix::Matrix::<f64>::new(vec![1e20, 0.0, 0.0, 1.0], 2, 2).unwrap();
+    assert!(!a.lu().unwrap().is_singular());
+    assert_eq!(a.det().unwrap(), 1e20);
+    let b = matrix::Matrix::<f64>::new(vec![1e20, 1.0], 2, 1).unwrap();
+    assert_eq!(a.solve(&b).unwrap().arr, vec![1.0, 1.0]);
+    assert_eq!(a.inverse().unwrap().arr, vec![1e-20, 0.0, 0.0, 1.0]);
+
+    let a = matrix::Matrix::<f32>::new(vec![1e9, 0.0, 0.0, 1.0], 2, 2).unwrap();
+    assert_eq!(a.det().unwrap(), 1e9);
+    let b = matrix::Matrix::<f32>::new(vec![1e9, 1.0], 2, 1).unwrap();
+    assert_eq!(a.solve(&b).unwrap().arr, vec![1.0, 1.0]);
+
+    // Not finite entries make the matrix singular
+    let a = matrix::Matrix::<f64>::new(vec![f64::NAN, 1.0, 1.0, 1.0], 2, 2).unwrap();
+    assert!(a.lu().unwrap().is_singular());
+}
+
+#[test]
+fn lu_singular_up_to_rounding() {
+    // The elimination leaves a last pivot of about 1e-16 instead of 0
+    let a = matrix::Matrix::<f64>::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3)
+        .unwrap();
+    assert!(a.lu().unwrap().is_singular());
+    assert_eq!(a.det().unwrap(), 0.0);
+    match a.inverse() {
+        Err(matrix::MatrixError::Singular(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    let b = matrix::Matrix::<f64>::new(vec![1.0, 2.0, 3.0], 3, 1).unwrap();
+    match a.solve(&b) {
+        Err(matrix::MatrixError::Singular(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+#[test]
+fn lu_errors() {
+    let a = matrix::Matrix::<f64>::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3).unwrap();
+    match a.det() {
+        Err(matrix::MatrixError::UncompatibleMatrixShapes(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    let b = matrix::Matrix::<f64>::identity(3).unwrap();
+    let c = matrix::Matrix::<f64>::new(vec![1.0, 2.0], 2, 1).unwrap();
+    match b.solve(&c) {
+        Err(matrix::MatrixError::UncompatibleMatrixShapes(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
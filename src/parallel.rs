@@ -0,0 +1,25 @@
+//! This module gathers the helpers used to spread computations over several threads.
+//!
+//! With the parallel feature, they use rayon; without it, they run sequentially. Each task always computes its result
+//! with the same operations in the same order, so that results don't depend on the number of threads or on the
+//! scheduling.
+
+/// Computes f(0), ..., f(count - 1), in parallel with the parallel feature.
+///
+/// Each f(n) runs on a single thread, so the results are the same with and without the parallel feature, as long as
+/// f itself doesn't depend on the order in which the indices are processed.
+pub(crate) fn map_indices<U, F>(count: usize, f: F) -> Vec<U>
+where
+    U: Send,
+    F: Fn(usize) -> U + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        (0..count).into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..count).map(f).collect()
+    }
+}
@@ -0,0 +1,278 @@
+//! This module implements the algebraic notation of polynomials, such as "3x^2 + (1+2i)x - 4".
+//!
+//! Polynomials can be printed in this notation with a configurable variable name and precision, and parsed back
+//! via the FromStr trait. Zero terms are skipped, as well as coefficients equal to one.
+
+use crate::algebra::CommutativeRing;
+use crate::complex::{Complex, RealNumber};
+use crate::polynomial::{ModularArithmeticPolynomial, Polynomial, PolynomialError, RingKind};
+
+use std::str::FromStr;
+
+/// Parameters of the algebraic notation
+#[derive(Clone, Debug)]
+pub struct AlgebraicFormat {
+    /// Name of the variable, "x" by default
+    pub variable: String,
+    /// Number of decimals printed for each coefficient, or None for the shortest exact representation
+    pub precision: Option<usize>,
+}
+
+impl Default for AlgebraicFormat {
+    fn default() -> Self {
+        Self {
+            variable: "x".to_string(),
+            precision: None,
+        }
+    }
+}
+
+/// Trait for coefficients that can be written and read in algebraic notation
+pub trait AlgebraicCoefficient: CommutativeRing {
+    /// Returns whether the term must be preceded by a minus sign, and the representation of the coefficient
+    /// without that sign. Coefficients that need it (like complex numbers) are wrapped in parenthesis.
+    fn to_algebraic(&self, precision: Option<usize>) -> (bool, String);
+    /// Parses a coefficient, either a plain number or the content of a parenthesis
+    fn from_algebraic(s: &str) -> Option<Self>;
+}
+
+/// Formats a real number, without its sign
+fn format_real<T: RealNumber>(x: T, precision: Option<usize>) -> (bool, String) {
+    let negative = x < T::zero();
+    let abs = if negative { -x } else { x };
+    match precision {
+        Some(p) => (negative, format!("{:.*}", p, abs)),
+        None => (negative, abs.to_string()),
+    }
+}
+
+/// Parses a real number, accepting an explicit leading plus sign
+fn parse_real<T: FromStr>(s: &str) -> Option<T> {
+    s.strip_prefix('+').unwrap_or(s).parse::<T>().ok()
+}
+
+impl AlgebraicCoefficient for f32 {
+    fn to_algebraic(&self, precision: Option<usize>) -> (bool, String) {
+        format_real(*self, precision)
+    }
+    fn from_algebraic(s: &str) -> Option<Self> {
+        parse_real(s)
+    }
+}
+
+impl AlgebraicCoefficient for f64 {
+    fn to_algebraic(&self, precision: Option<usize>) -> (bool, String) {
+        format_real(*self, precision)
+    }
+    fn from_algebraic(s: &str) -> Option<Self> {
+        parse_real(s)
+    }
+}
+
+/// Complex numbers are printed as real numbers when their imaginary part is zero, as "2i" when their real part is
+/// zero, and as "(1+2i)" otherwise.
+impl<T: RealNumber + AlgebraicCoefficient> AlgebraicCoefficient for Complex<T> {
+    fn to_algebraic(&self, precision: Option<usize>) -> (bool, String) {
+        let zero = T::zero();
+        if self.imag() == zero {
+            return self.real().to_algebraic(precision);
+        }
+
+        let (imag_negative, mut imag) = self.imag().to_algebraic(precision);
+        if imag == "1" {
+            imag.clear();
+        }
+        if self.real() == zero {
+            return (imag_negative, format!("{}i", imag));
+        }
+
+        let (real_negative, real) = self.real().to_algebraic(precision);
+        let real_sign = if real_negative { "-" } else { "" };
+        let imag_sign = if imag_negative { "-" } else { "+" };
+        (
+            false,
+            format!("({}{}{}{}i)", real_sign, real, imag_sign, imag),
+        )
+    }
+
+    fn from_algebraic(s: &str) -> Option<Self> {
+        let zero = T::zero();
+        let imag_str = match s.strip_suffix('i') {
+            Some(imag_str) => imag_str,
+            None => return T::from_algebraic(s).map(|r| Complex::new(r, zero)),
+        };
+
+        // The imaginary part starts at the last sign which is not the leading one, nor part of an exponent
+        let split = imag_str
+            .char_indices()
+            .rev()
+            .find(|&(n, c)| (c == '+' || c == '-') && n > 0 && !imag_str[..n].ends_with(['e', 'E']))
+            .map(|(n, _)| n)
+            .unwrap_or(0);
+        let (real_str, imag_str) = imag_str.split_at(split);
+
+        let real = if real_str.is_empty() {
+            zero
+        } else {
+            T::from_algebraic(real_str)?
+        };
+        let imag = match imag_str {
+            "" | "+" => T::one(),
+            "-" => -T::one(),
+            _ => T::from_algebraic(imag_str)?,
+        };
+        Some(Complex::new(real, imag))
+    }
+}
+
+impl<T: AlgebraicCoefficient> Polynomial<T> {
+    /// Writes the polynomial in algebraic notation, highest degree first, such as "3x^2 + (1+2i)x - 4"
+    pub fn to_algebraic_string(&self, format: &AlgebraicFormat) -> String {
+        let mut ret = String::new();
+        for (deg, coef) in self.coefs.iter().enumerate().rev() {
+            if *coef == T::zero() {
+                continue;
+            }
+
+            let (negative, mut coef_str) = coef.to_algebraic(format.precision);
+            let is_one = *coef == T::one() || *coef == -T::one();
+            if deg > 0 && is_one {
+                coef_str.clear();
+            }
+
+            match (ret.is_empty(), negative) {
+                (true, true) => ret.push('-'),
+                (true, false) => (),
+                (false, true) => ret.push_str(" - "),
+                (false, false) => ret.push_str(" + "),
+            }
+            ret.push_str(&coef_str);
+            match deg {
+                0 => (),
+                1 => ret.push_str(&format.variable),
+                _ => ret.push_str(&format!("{}^{}", format.variable, deg)),
+            }
+        }
+
+        if ret.is_empty() {
+            ret.push('0');
+        }
+        ret
+    }
+
+    /// Parses a polynomial written in algebraic notation with the given variable name.
+    ///
+    /// Terms are separated by + or - signs, in any order, and terms of the same degree are added together. A term
+    /// is a coefficient (a number, or a complex number in parenthesis), optionally followed by the variable and
+    /// its degree, like "3", "(1+2i)x", "-x^4" or "2.5*x^3". Whitespaces are ignored.
+    pub fn parse(s: &str, variable: &str) -> Result<Polynomial<T>, PolynomialError> {
+        let parse_error = |msg: &str| PolynomialError::ParseError(format!("{}, in \"{}\"", msg, s));
+        let input: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if variable.is_empty() {
+            return Err(parse_error("Empty variable name"));
+        }
+        if input.is_empty() {
+            return Err(parse_error("Empty polynomial"));
+        }
+
+        let mut coefs = Vec::<T>::new();
+        let mut rest = input.as_str();
+        while !rest.is_empty() {
+            // Sign of the term: mandatory between terms, optional for the first one
+            let negative = rest.starts_with('-');
+            if rest.starts_with(['+', '-']) {
+                rest = &rest[1..];
+            } else if !coefs.is_empty() {
+                return Err(parse_error("Missing sign between terms"));
+            }
+
+            // Coefficient of the term, if any
+            let coef_str;
+            if let Some(parenthesis) = rest.strip_prefix('(') {
+                let end = parenthesis
+                    .find(')')
+                    .ok_or_else(|| parse_error("Unclosed parenthesis"))?;
+                coef_str = &parenthesis[..end];
+                rest = &parenthesis[end + 1..];
+            } else {
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .unwrap_or(rest.len());
+                // A trailing i is the imaginary unit, unless it is the variable
+                let end = if rest[end..].starts_with('i') && !rest[end..].starts_with(variable) {
+                    end + 1
+                } else {
+                    end
+                };
+                coef_str = &rest[..end];
+                rest = &rest[end..];
+            }
+            let mut coef = if coef_str.is_empty() {
+                T::one()
+            } else {
+                T::from_algebraic(coef_str)
+                    .ok_or_else(|| parse_error(&format!("Invalid coefficient {}", coef_str)))?
+            };
+            if negative {
+                coef = -coef;
+            }
+
+            // Variable and degree of the term, if any
+            let with_variable = rest.strip_prefix('*').unwrap_or(rest);
+            let deg = if let Some(after_variable) = with_variable.strip_prefix(variable) {
+                rest = after_variable;
+                if let Some(exponent) = rest.strip_prefix('^') {
+                    let end = exponent
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(exponent.len());
+                    rest = &exponent[end..];
+                    exponent[..end]
+                        .parse::<usize>()
+                        .map_err(|_| parse_error("Invalid degree"))?
+                } else {
+                    1
+                }
+            } else if coef_str.is_empty() {
+                return Err(parse_error("Empty term"));
+            } else {
+                0
+            };
+
+            if coefs.len() <= deg {
+                coefs.resize(deg + 1, T::zero());
+            }
+            coefs[deg] += coef;
+        }
+
+        Ok(Polynomial { coefs })
+    }
+}
+
+/// Parses a polynomial in algebraic notation, with variable x
+impl<T: AlgebraicCoefficient> FromStr for Polynomial<T> {
+    type Err = PolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Polynomial::parse(s, "x")
+    }
+}
+
+impl<T: AlgebraicCoefficient> ModularArithmeticPolynomial<T> {
+    /// Writes the polynomial in algebraic notation, followed by the ring modulus, such as "x + 1 mod (x^3 - 1)"
+    pub fn to_algebraic_string(&self, format: &AlgebraicFormat) -> String {
+        let sign = match self.kind {
+            RingKind::Cyclic => '-',
+            RingKind::Negacyclic => '+',
+        };
+        let variable = match self.modulus() {
+            1 => format.variable.clone(),
+            n => format!("{}^{}", format.variable, n),
+        };
+        format!(
+            "{} mod ({} {} 1)",
+            self.polynomial.to_algebraic_string(format),
+            variable,
+            sign
+        )
+    }
+}
@@ -1,22 +1,563 @@
 //! This module implements different versions of the convolution operation
 
-use std::ops::{AddAssign, Mul};
+use crate::algebra::{CommutativeRing, Ring};
+use crate::complex::RealNumber;
+use crate::polynomial::fft::{factorize, FftPlanner};
+use crate::polynomial::ntt::{cyclic_convolution_via_ntt, linear_convolution_via_ntt};
+use crate::polynomial::tuning;
+use crate::prime_field::Zp;
+
+/// Rings in which the Toom-3 interpolation can be done: it divides by 2 and 3 values which are exact multiples of
+/// them in the integers, so that any ring where 2 and 3 are invertible or exact divisors can implement it.
+pub trait ToomCook: Ring {
+    /// Division by 2 or 3 of a value which is a multiple of the divisor
+    fn div_exact(self, divisor: u8) -> Self;
+
+    /// Whether the Toom-3 convolution can be used, which is not the case in characteristic 2 or 3
+    fn supports_toom3() -> bool {
+        true
+    }
+}
+
+impl ToomCook for f32 {
+    fn div_exact(self, divisor: u8) -> Self {
+        self / divisor as f32
+    }
+}
+
+impl ToomCook for f64 {
+    fn div_exact(self, divisor: u8) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl<T: RealNumber> ToomCook for complex::Complex<T> {
+    fn div_exact(self, divisor: u8) -> Self {
+        let divisor = T::from_integer(divisor as i64);
+        complex::Complex::new(self.real() / divisor, self.imag() / divisor)
+    }
+}
+
+impl ToomCook for i64 {
+    fn div_exact(self, divisor: u8) -> Self {
+        self / divisor as i64
+    }
+}
+
+impl ToomCook for i128 {
+    fn div_exact(self, divisor: u8) -> Self {
+        self / divisor as i128
+    }
+}
+
+impl<const P: u64> ToomCook for Zp<P> {
+    fn div_exact(self, divisor: u8) -> Self {
+        // The inverse of the divisor is (kP + 1) / divisor, for the k < divisor making it an integer
+        let divisor = divisor as u128;
+        let inverse = (1..divisor)
+            .map(|k| k * P as u128 + 1)
+            .find(|multiple| multiple % divisor == 0)
+            .expect("Divisors are invertible when Toom-3 is supported")
+            / divisor;
+        self * Zp::new(inverse as u64)
+    }
+
+    fn supports_toom3() -> bool {
+        P > 3
+    }
+}
+
+/// Estimated cost of a fast convolution with an output of size n, in units of n log2(n) naive multiply-adds
+const FAST_COST_FACTOR: f64 = 4.0;
+
+/// Algorithms between which the dispatching convolutions choose
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConvolutionAlgorithm {
+    /// School book convolution, in O(n^2)
+    Naive,
+    /// Karatsuba convolution, in O(n^1.58)
+    Karatsuba,
+    /// Toom-3 convolution, in O(n^1.46)
+    Toom3,
+    /// Fast convolution of the coefficient type (fft or ntt), in O(n log(n)), see FastConvolution
+    Fast,
+}
+
+/// Sizes at which the dispatching convolutions switch from one algorithm to the next
+///
+/// Each algorithm is used above its threshold and up to the threshold of the next one: naive, Karatsuba, Toom-3,
+/// then the fast convolution of the coefficient type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConvolutionThresholds {
+    /// Above this size for the shortest input, Karatsuba replaces the naive convolution
+    pub karatsuba: usize,
+    /// Above this size for the shortest input, Toom-3 replaces Karatsuba
+    pub toom3: usize,
+    /// Above this size for the longest input, the fast linear convolution is used
+    pub fast: usize,
+    /// Above this size, the fast cyclic convolution is used for products modulo x^n - 1
+    pub fast_cyclic: usize,
+}
+
+impl ConvolutionThresholds {
+    /// Thresholds which always select the given algorithm, whatever the size
+    pub fn forcing(algorithm: ConvolutionAlgorithm) -> Self {
+        let (karatsuba, toom3, fast) = match algorithm {
+            ConvolutionAlgorithm::Naive => (usize::MAX, usize::MAX, usize::MAX),
+            ConvolutionAlgorithm::Karatsuba => (0, usize::MAX, usize::MAX),
+            ConvolutionAlgorithm::Toom3 => (0, 0, usize::MAX),
+            ConvolutionAlgorithm::Fast => (0, 0, 0),
+        };
+        Self {
+            karatsuba,
+            toom3,
+            fast,
+            fast_cyclic: fast,
+        }
+    }
+
+    /// Algorithm used by the cyclic convolution of two inputs of the given size
+    pub fn cyclic_algorithm(&self, size: usize) -> ConvolutionAlgorithm {
+        if size > self.fast_cyclic {
+            ConvolutionAlgorithm::Fast
+        } else if size > self.toom3 {
+            ConvolutionAlgorithm::Toom3
+        } else if size > self.karatsuba {
+            ConvolutionAlgorithm::Karatsuba
+        } else {
+            ConvolutionAlgorithm::Naive
+        }
+    }
+
+    /// Algorithm used by the linear convolution of two inputs of the given sizes
+    ///
+    /// Above the fast threshold for the longest input, a shortest input below the Karatsuba threshold still uses the
+    /// fast convolution, unless the naive one is cheaper: its cost grows with the product of the sizes, while the one
+    /// of the fast convolution is about FAST_COST_FACTOR * n log2(n), for the size n of the output.
+    pub fn linear_algorithm(&self, a_size: usize, b_size: usize) -> ConvolutionAlgorithm {
+        let shortest = usize::min(a_size, b_size);
+        let longest = usize::max(a_size, b_size);
+        let naive_is_cheaper = || {
+            let size = (a_size + b_size) as f64;
+            ((shortest * longest) as f64) < FAST_COST_FACTOR * size * size.log2()
+        };
+        if shortest == 0 {
+            ConvolutionAlgorithm::Naive
+        } else if longest > self.fast && (shortest > self.karatsuba || !naive_is_cheaper()) {
+            ConvolutionAlgorithm::Fast
+        } else if shortest <= self.karatsuba {
+            ConvolutionAlgorithm::Naive
+        } else if shortest > self.toom3 {
+            ConvolutionAlgorithm::Toom3
+        } else {
+            ConvolutionAlgorithm::Karatsuba
+        }
+    }
+}
+
+/// Fast convolution algorithm of a coefficient type, used by the dispatching convolutions for high degrees
+///
+/// Floating point types (real or complex) use the fft, and prime fields use the number theoretic transform,
+/// which keeps products exact. Any other commutative ring can implement it with the Toom-3 convolution.
+pub trait FastConvolution: CommutativeRing + ToomCook + 'static {
+    /// Default thresholds of the dispatching convolutions for this type. They are rough values, which can be
+    /// overridden at runtime or measured on the current machine, see the tuning module. Types with a transform
+    /// switch to it before Toom-3 would win over Karatsuba, and set the toom3 threshold to usize::MAX.
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 32,
+        toom3: 128,
+        fast: 1024,
+        fast_cyclic: 130,
+    };
+
+    /// Full product of the two inputs, which can have different sizes: the output has size a.len() + b.len() - 1
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self>;
+
+    /// Toom-3 tier of the dispatching convolutions, whatever the thresholds which select it.
+    ///
+    /// By default, this is toom3_linear_convolution. Types on which its intermediate values could overflow override
+    /// it, to evaluate it in a wider type or to fall back to Karatsuba.
+    fn toom3_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        toom3_linear_convolution(a, b)
+    }
+
+    /// Product modulo x^n - 1 of two inputs of the same size n: the output has size n.
+    ///
+    /// By default, the full product is computed and folded. Types with a transform override it to compute the
+    /// cyclic convolution directly with a transform of size n, which avoids padding to twice the size.
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        fold_cyclic(Self::fast_linear_convolution(a, b), a.len())
+    }
+}
+
+/// Real numbers use the real-input fft. Their naive convolutions are vectorized by the compiler, which keeps them
+/// competitive up to a few hundred coefficients, and the fft takes over from Karatsuba without a Toom-3 tier
+impl FastConvolution for f32 {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 256,
+        toom3: usize::MAX,
+        fast: 512,
+        fast_cyclic: 96,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        linear_convolution_via_real_fft(a, b)
+    }
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        cyclic_convolution_via_real_fft(a, b)
+    }
+}
+
+impl FastConvolution for f64 {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 256,
+        toom3: usize::MAX,
+        fast: 512,
+        fast_cyclic: 96,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        linear_convolution_via_real_fft(a, b)
+    }
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        cyclic_convolution_via_real_fft(a, b)
+    }
+}
+
+/// Complex numbers use the fft from a few dozen coefficients, without a Toom-3 tier
+impl FastConvolution for complex::Complex<f32> {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 32,
+        toom3: usize::MAX,
+        fast: 64,
+        fast_cyclic: 32,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        linear_convolution_via_fft(a, b)
+    }
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        cyclic_convolution_via_fft(a, b)
+    }
+}
+
+impl FastConvolution for complex::Complex<f64> {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 32,
+        toom3: usize::MAX,
+        fast: 64,
+        fast_cyclic: 32,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        linear_convolution_via_fft(a, b)
+    }
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        cyclic_convolution_via_fft(a, b)
+    }
+}
+
+/// Integers have no fast transform: high degrees use the Toom-3 convolution, evaluated in i128. Its evaluations and
+/// the products of values grow well beyond the coefficients of the result, and would overflow in i64 long before the
+/// result does. The Toom-3 tier is never run on i64 itself, and Karatsuba is used up to the fast threshold.
+impl FastConvolution for i64 {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 96,
+        toom3: usize::MAX,
+        fast: 256,
+        fast_cyclic: 256,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        <i64 as FastConvolution>::toom3_linear_convolution(a, b)
+    }
+    fn toom3_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        let a: Vec<i128> = a.iter().map(|&x| x as i128).collect();
+        let b: Vec<i128> = b.iter().map(|&x| x as i128).collect();
+        toom3_linear_convolution(&a, &b)
+            .into_iter()
+            .map(|x| i64::try_from(x).expect("Convolution overflows i64"))
+            .collect()
+    }
+}
+
+/// There is no wider type to evaluate Toom-3 in, so i128 stays on the naive and Karatsuba convolutions
+impl FastConvolution for i128 {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 64,
+        toom3: usize::MAX,
+        fast: usize::MAX,
+        fast_cyclic: usize::MAX,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        karatsuba_linear_convolution(a, b)
+    }
+    fn toom3_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        karatsuba_linear_convolution(a, b)
+    }
+}
+
+/// Prime fields use the ntt from about a hundred coefficients, without a Toom-3 tier
+impl<const P: u64> FastConvolution for Zp<P> {
+    const THRESHOLDS: ConvolutionThresholds = ConvolutionThresholds {
+        karatsuba: 32,
+        toom3: usize::MAX,
+        fast: 96,
+        fast_cyclic: 64,
+    };
+    fn fast_linear_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        linear_convolution_via_ntt(a, b)
+    }
+    fn fast_cyclic_convolution(a: &[Self], b: &[Self]) -> Vec<Self> {
+        cyclic_convolution_via_ntt(a, b)
+    }
+}
 
 /// The convolution actually used for polynomial multiplication
 ///
-/// It performs a classic convolution for low degrees, and an fft-based convolution for higher degrees.
-/// The threshold that controls the decision is based on a crude analysis done via timing the different versions on my
-/// personal computer.
-pub fn convolution<T>(a: &Vec<T>, b: &Vec<T>) -> Vec<T>
+/// It performs a classic convolution for low degrees, a Karatsuba then a Toom-3 convolution (folded modulo x^n - 1)
+/// for medium degrees, and a fast cyclic convolution (fft or ntt, see FastConvolution) for higher degrees. The
+/// thresholds that control the decision depend on the coefficient type, and can be changed at runtime or measured on
+/// the current machine, see the tuning module.
+pub fn convolution<T: FastConvolution>(a: &[T], b: &[T]) -> Vec<T> {
+    convolution_with_thresholds(a, b, &tuning::thresholds::<T>())
+}
+
+/// The convolution used for polynomial multiplication, with the given thresholds instead of the global ones
+pub fn convolution_with_thresholds<T: FastConvolution>(
+    a: &[T],
+    b: &[T],
+    thresholds: &ConvolutionThresholds,
+) -> Vec<T> {
+    assert!(a.len() == b.len());
+    let size = a.len();
+    match thresholds.cyclic_algorithm(size) {
+        ConvolutionAlgorithm::Naive => convolution_for_polynomial_mult_in_modular_arithmetic(a, b),
+        ConvolutionAlgorithm::Karatsuba => fold_cyclic(karatsuba_linear_convolution(a, b), size),
+        ConvolutionAlgorithm::Toom3 => fold_cyclic(T::toom3_linear_convolution(a, b), size),
+        ConvolutionAlgorithm::Fast => T::fast_cyclic_convolution(a, b),
+    }
+}
+
+/// Below this size, the recursion of the Karatsuba convolution stops with a naive convolution
+const KARATSUBA_THRESHOLD: usize = 32;
+/// Below this size, the recursion of the Toom-3 convolution stops with a Karatsuba convolution
+const TOOM3_THRESHOLD: usize = 128;
+
+/// The convolution used when the full product is needed, without any modular folding
+///
+/// Inputs can have different sizes, and the output has size a.len() + b.len() - 1. It performs a naive convolution
+/// when one of the inputs is small, a Karatsuba then a Toom-3 convolution for medium sizes, and a fast convolution
+/// (fft or ntt) for higher sizes. As for `convolution`, the thresholds depend on the coefficient type.
+pub fn linear_convolution<T: FastConvolution>(a: &[T], b: &[T]) -> Vec<T> {
+    linear_convolution_with_thresholds(a, b, &tuning::thresholds::<T>())
+}
+
+/// The convolution used when the full product is needed, with the given thresholds instead of the global ones
+pub fn linear_convolution_with_thresholds<T: FastConvolution>(
+    a: &[T],
+    b: &[T],
+    thresholds: &ConvolutionThresholds,
+) -> Vec<T> {
+    match thresholds.linear_algorithm(a.len(), b.len()) {
+        ConvolutionAlgorithm::Naive => naive_linear_convolution(a, b),
+        ConvolutionAlgorithm::Karatsuba => karatsuba_linear_convolution(a, b),
+        ConvolutionAlgorithm::Toom3 => T::toom3_linear_convolution(a, b),
+        ConvolutionAlgorithm::Fast => T::fast_linear_convolution(a, b),
+    }
+}
+
+/// A naive school book convolution, accepting inputs of different sizes
+pub fn naive_linear_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ring,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut convolution = vec![T::zero(); a.len() + b.len() - 1];
+    for (i, &aa) in a.iter().enumerate() {
+        for (dst, &bb) in convolution[i..].iter_mut().zip(b.iter()) {
+            *dst += aa * bb;
+        }
+    }
+    convolution
+}
+
+/// Karatsuba convolution, accepting inputs of different sizes
+///
+/// The longest input is cut in chunks of the size of the shortest one, and each chunk product is computed
+/// with the Karatsuba algorithm.
+pub fn karatsuba_linear_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T> + From<complex::Complex<f64>>,
-    FftComplex: From<T>,
+    T: Ring,
 {
-    if a.len() > 130 {
-        return convolution_via_fft(a, b);
-    } else {
-        return convolution_for_polynomial_mult_in_modular_arithmetic(a, b);
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let chunk_size = short.len();
+
+    let mut convolution = vec![T::zero(); a.len() + b.len() - 1];
+    let mut padded_chunk = vec![T::zero(); chunk_size];
+    for (n, chunk) in long.chunks(chunk_size).enumerate() {
+        padded_chunk[..chunk.len()].copy_from_slice(chunk);
+        padded_chunk[chunk.len()..].fill(T::zero());
+        let offset = n * chunk_size;
+        let product = karatsuba_convolution(&padded_chunk, short);
+        for (dst, &val) in convolution[offset..].iter_mut().zip(product.iter()) {
+            *dst += val;
+        }
     }
+    convolution
+}
+
+/// Karatsuba convolution for inputs of the same size, with complexity O(n^1.58)
+///
+/// Each input is split in a low and a high half, and the product only needs 3 half-size products:
+/// (a0 + a1 x)(b0 + b1 x) = a0b0 + ((a0 + a1)(b0 + b1) - a0b0 - a1b1) x + a1b1 x²
+pub fn karatsuba_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ring,
+{
+    assert!(a.len() == b.len());
+    let size = a.len();
+    if size <= KARATSUBA_THRESHOLD {
+        return naive_linear_convolution(a, b);
+    }
+
+    let half = size / 2;
+    let (a_low, a_high) = a.split_at(half);
+    let (b_low, b_high) = b.split_at(half);
+
+    let low = karatsuba_convolution(a_low, b_low);
+    let high = karatsuba_convolution(a_high, b_high);
+
+    // The high halves are at least as long as the low halves
+    let mut a_sum = a_high.to_vec();
+    let mut b_sum = b_high.to_vec();
+    for i in 0..half {
+        a_sum[i] += a_low[i];
+        b_sum[i] += b_low[i];
+    }
+    let mut middle = karatsuba_convolution(&a_sum, &b_sum);
+    for (dst, &val) in middle.iter_mut().zip(low.iter()) {
+        *dst -= val;
+    }
+    for (dst, &val) in middle.iter_mut().zip(high.iter()) {
+        *dst -= val;
+    }
+
+    let mut convolution = vec![T::zero(); 2 * size - 1];
+    for (dst, &val) in convolution.iter_mut().zip(low.iter()) {
+        *dst += val;
+    }
+    for (dst, &val) in convolution[half..].iter_mut().zip(middle.iter()) {
+        *dst += val;
+    }
+    for (dst, &val) in convolution[2 * half..].iter_mut().zip(high.iter()) {
+        *dst += val;
+    }
+    convolution
+}
+
+/// Toom-3 convolution, accepting inputs of different sizes
+///
+/// As for the Karatsuba convolution, the longest input is cut in chunks of the size of the shortest one.
+pub fn toom3_linear_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: ToomCook,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let chunk_size = short.len();
+
+    let mut convolution = vec![T::zero(); a.len() + b.len() - 1];
+    let mut padded_chunk = vec![T::zero(); chunk_size];
+    for (n, chunk) in long.chunks(chunk_size).enumerate() {
+        padded_chunk[..chunk.len()].copy_from_slice(chunk);
+        padded_chunk[chunk.len()..].fill(T::zero());
+        let offset = n * chunk_size;
+        let product = toom3_convolution(&padded_chunk, short);
+        for (dst, &val) in convolution[offset..].iter_mut().zip(product.iter()) {
+            *dst += val;
+        }
+    }
+    convolution
+}
+
+/// Toom-3 convolution for inputs of the same size, with complexity O(n^1.46)
+///
+/// Each input is split in 3 parts, seen as a polynomial of degree 2 in y = x^k. Both polynomials are evaluated at
+/// 0, 1, -1, -2 and infinity, the 5 products of values are computed recursively, and the product of degree 4 is
+/// interpolated back from them. The interpolation sequence is the one of Bodrato, which only divides by 2 and 3.
+pub fn toom3_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: ToomCook,
+{
+    assert!(a.len() == b.len());
+    let size = a.len();
+    if size <= TOOM3_THRESHOLD || !T::supports_toom3() {
+        return karatsuba_convolution(a, b);
+    }
+
+    let part_size = size.div_ceil(3);
+    let a_values = toom3_evaluate(a, part_size);
+    let b_values = toom3_evaluate(b, part_size);
+    let mut products = a_values
+        .iter()
+        .zip(b_values.iter())
+        .map(|(a_value, b_value)| toom3_convolution(a_value, b_value));
+    let mut next_product = || products.next().expect("5 evaluation points");
+    let (r0, mut r1, mut r2, mut r3, r4) = (
+        next_product(),
+        next_product(),
+        next_product(),
+        next_product(),
+        next_product(),
+    );
+
+    // r1 = r(1), r2 = r(-1), r3 = r(-2) are turned into the coefficients of degree 1, 2 and 3
+    for i in 0..r0.len() {
+        let r_minus_1 = r2[i];
+        r3[i] = (r3[i] - r1[i]).div_exact(3);
+        r1[i] = (r1[i] - r_minus_1).div_exact(2);
+        r2[i] = r_minus_1 - r0[i];
+        r3[i] = (r2[i] - r3[i]).div_exact(2) + r4[i] + r4[i];
+        r2[i] += r1[i] - r4[i];
+        r1[i] -= r3[i];
+    }
+
+    let mut convolution = vec![T::zero(); 6 * part_size - 1];
+    for (n, coefs) in [r0, r1, r2, r3, r4].iter().enumerate() {
+        for (dst, &val) in convolution[n * part_size..].iter_mut().zip(coefs.iter()) {
+            *dst += val;
+        }
+    }
+    // The parts were padded with zeros, so the extra high coefficients are zero
+    convolution.truncate(2 * size - 1);
+    convolution
+}
+
+/// Values at 0, 1, -1, -2 and infinity of a0 + a1 y + a2 y^2, where the input is split in parts of the given size
+fn toom3_evaluate<T: ToomCook>(a: &[T], part_size: usize) -> [Vec<T>; 5] {
+    let mut parts = a.chunks(part_size).map(|part| {
+        let mut part = part.to_vec();
+        part.resize(part_size, T::zero());
+        part
+    });
+    let a0 = parts.next().unwrap_or_else(|| vec![T::zero(); part_size]);
+    let a1 = parts.next().unwrap_or_else(|| vec![T::zero(); part_size]);
+    let a2 = parts.next().unwrap_or_else(|| vec![T::zero(); part_size]);
+
+    let mut at_1 = Vec::with_capacity(part_size);
+    let mut at_minus_1 = Vec::with_capacity(part_size);
+    let mut at_minus_2 = Vec::with_capacity(part_size);
+    for i in 0..part_size {
+        let even = a0[i] + a2[i];
+        at_1.push(even + a1[i]);
+        at_minus_1.push(even - a1[i]);
+        let doubled = at_minus_1[i] + a2[i];
+        at_minus_2.push(doubled + doubled - a0[i]);
+    }
+    [a0, at_1, at_minus_1, at_minus_2, a2]
 }
 
 /// This is a convolution implementation specifically designed for a modular arithmetic.
@@ -24,14 +565,14 @@ where
 /// In particular, the size of the output is the same size as the input: any higher
 /// order term is "spilling over" in lower order terms. Each resulting term
 /// has an equal number of addition.
-pub fn convolution_for_polynomial_mult_in_modular_arithmetic<T>(a: &Vec<T>, b: &Vec<T>) -> Vec<T>
+pub fn convolution_for_polynomial_mult_in_modular_arithmetic<T>(a: &[T], b: &[T]) -> Vec<T>
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T>,
+    T: Ring,
 {
     assert!(a.len() == b.len());
     let size = a.len();
-    let b_rev: Vec<T> = b.into_iter().rev().copied().collect::<Vec<T>>();
-    let mut convolution = vec![T::from(0.0); size];
+    let b_rev: Vec<T> = b.iter().rev().copied().collect::<Vec<T>>();
+    let mut convolution = vec![T::zero(); size];
 
     _naive_convolution_with_reversed_signal_begin(&a, &b_rev, &mut convolution[0..size], size);
     _naive_convolution_with_reversed_signal_end(&a, &b_rev, &mut convolution[0..size], size);
@@ -39,16 +580,29 @@ where
     return convolution;
 }
 
+/// Folds a full product into a product modulo x^size - 1: the coefficient of degree size + k is added to the one of
+/// degree k.
+pub fn fold_cyclic<T: Ring>(mut product: Vec<T>, size: usize) -> Vec<T> {
+    if product.len() <= size {
+        product.resize(size, T::zero());
+        return product;
+    }
+    let high = product.split_off(size);
+    for (k, value) in high.into_iter().enumerate() {
+        product[k % size] += value;
+    }
+    product
+}
+
 /// This a straight up naive school book convolution
-#[allow(dead_code)]
 pub fn naive_convolution<T>(a: &Vec<T>, b: &Vec<T>) -> Vec<T>
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T>,
+    T: Ring,
 {
     assert!(a.len() == b.len());
     let size = a.len();
     let b_rev: Vec<T> = b.into_iter().rev().copied().collect::<Vec<T>>();
-    let mut convolution = vec![T::from(0.0); 2 * size - 1];
+    let mut convolution = vec![T::zero(); 2 * size - 1];
 
     _naive_convolution_with_reversed_signal_begin(&a, &b_rev, &mut convolution[0..size], size);
     _naive_convolution_with_reversed_signal_end(
@@ -63,7 +617,7 @@ where
 
 fn _naive_convolution_with_reversed_signal_begin<T>(a: &[T], b: &[T], dst: &mut [T], size: usize)
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T>,
+    T: Ring,
 {
     for deg in 0..size {
         dst[deg] += _scalar_product(&a[..deg + 1], &b[size - deg - 1..]);
@@ -71,7 +625,7 @@ where
 }
 fn _naive_convolution_with_reversed_signal_end<T>(a: &[T], b: &[T], dst: &mut [T], size: usize)
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T>,
+    T: Ring,
 {
     for deg in 0..size - 1 {
         dst[deg] += _scalar_product(&a[deg + 1..], &b[..size - deg - 1]);
@@ -80,9 +634,9 @@ where
 
 fn _scalar_product<T>(a: &[T], b: &[T]) -> T
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T>,
+    T: Ring,
 {
-    let mut ret = T::from(0.0);
+    let mut ret = T::zero();
     for (&aa, &bb) in a.iter().zip(b.iter()) {
         ret += aa * bb;
     }
@@ -100,15 +654,29 @@ type FftComplex = complex::Complex<f64>;
 /// In the context of polynomials in particular, the forward Fourier transform is converting between the coefficient representation
 /// of the polynomial to the point-value representation at the roots of unity. The backward Fourier transform is then the
 /// interpolation of the point-value representation, to get the coefficient representation.
-pub fn convolution_via_fft<T>(a: &Vec<T>, b: &Vec<T>) -> Vec<T>
+pub fn convolution_via_fft<T>(a: &[T], b: &[T]) -> Vec<T>
 where
-    T: Clone + Copy + From<f32> + AddAssign + Mul<Output = T> + From<complex::Complex<f64>>,
+    T: Ring + From<complex::Complex<f64>>,
     FftComplex: From<T>,
 {
-    let size = a.len();
-    let target_size = next_power_of_2(2 * a.len());
+    linear_convolution_via_fft(a, b)
+}
 
-    // Copy coefs in complex form, and pad with zeros to get target size: the first power of 2 above 2*size
+/// The fft-based convolution, accepting inputs of different sizes.
+///
+/// Both inputs are padded with zeros up to the first power of 2 above the output size.
+pub fn linear_convolution_via_fft<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ring + From<complex::Complex<f64>>,
+    FftComplex: From<T>,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let output_size = a.len() + b.len() - 1;
+    let target_size = next_power_of_2(output_size);
+
+    // Copy coefs in complex form, and pad with zeros to get target size
     let mut a_coefs = Vec::<FftComplex>::with_capacity(target_size);
     let mut b_coefs = Vec::<FftComplex>::with_capacity(target_size);
     for val in a {
@@ -117,158 +685,148 @@ where
     for val in b {
         b_coefs.push(FftComplex::from(*val));
     }
-    for _ in size..target_size {
-        a_coefs.push(FftComplex::from(T::from(0.0)));
-        b_coefs.push(FftComplex::from(T::from(0.0)));
-    }
+    a_coefs.resize(target_size, FftComplex::from(T::zero()));
+    b_coefs.resize(target_size, FftComplex::from(T::zero()));
 
-    return _convolution_via_fft(&a_coefs, &b_coefs)
+    _convolution_via_fft(a_coefs, b_coefs)
         .iter()
-        .take(2 * a.len() - 1)
+        .take(output_size)
         .map(|x| T::from(*x))
-        .collect::<Vec<T>>();
+        .collect::<Vec<T>>()
 }
 
-fn _convolution_via_fft(a: &Vec<FftComplex>, b: &Vec<FftComplex>) -> Vec<FftComplex> {
-    if a.len() == 0 {
+/// The fft-based cyclic convolution, where both inputs have the same size n and the output is the product modulo
+/// x^n - 1.
+///
+/// When n only has small prime factors, the transform is done at size n directly, which roughly halves its size
+/// and memory compared to the full product. Otherwise the full product is computed with padding, and folded.
+pub fn cyclic_convolution_via_fft<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ring + From<complex::Complex<f64>>,
+    FftComplex: From<T>,
+{
+    assert!(a.len() == b.len());
+    let size = a.len();
+    if size == 0 {
         return vec![];
-    } else if a.len() == 1 {
-        return vec![a[0] * b[0]];
+    }
+    if factorize(size).into_iter().max().unwrap_or(1) > CYCLIC_FFT_MAX_FACTOR {
+        return fold_cyclic(linear_convolution_via_fft(a, b), size);
     }
 
-    // Allocating buffer for fft signal
-    let mut a_fft = vec![FftComplex::from(0.0); a.len()];
-    let mut b_fft = vec![FftComplex::from(0.0); b.len()];
-
-    // This weird resorting allows separating even and odd indexed values of "a" via slice manipulation
-    let mut indices = vec![0; a.len()];
-    oddeven_sort(a.len(), &mut indices);
-    let sorted_a: Vec<FftComplex> = indices.iter().map(|n| a[*n]).collect();
-    let sorted_b: Vec<FftComplex> = indices.iter().map(|n| b[*n]).collect();
-
-    let mut roots = get_roots_of_unity(a.len());
-
-    _fft_forward(&sorted_a, &roots, &mut a_fft);
-    _fft_forward(&sorted_b, &roots, &mut b_fft);
-
-    // The term by term product in Fourier space is equivalent to the convolution in the vector space
-    let prod: Vec<FftComplex> = a_fft
+    let a_coefs = a.iter().map(|&x| FftComplex::from(x)).collect();
+    let b_coefs = b.iter().map(|&x| FftComplex::from(x)).collect();
+    _convolution_via_fft(a_coefs, b_coefs)
         .iter()
-        .zip(b_fft.iter())
-        .map(|(x, y)| *x * *y)
-        .collect();
-
-    // Again resorting to easily separate odd and even-indexed values
-    let sorted_prod: Vec<FftComplex> = indices.iter().map(|n| prod[*n]).collect();
-
-    _fft_backward(&sorted_prod, &mut roots, &mut a_fft);
-    return a_fft;
+        .map(|x| T::from(*x))
+        .collect::<Vec<T>>()
 }
 
-fn next_power_of_2(mut num: usize) -> usize {
-    let mut val: u32 = 0;
+/// Above this prime factor in the size, a direct cyclic fft is slower than a padded power of 2 one
+const CYCLIC_FFT_MAX_FACTOR: usize = 7;
 
-    num = num - 1;
+/// Real coefficient types, which can use the real-input fft
+pub trait RealFftCoefficient: Ring {
+    /// Value used by the transforms, which are computed in double precision
+    fn to_f64(self) -> f64;
+    /// Conversion of a value computed by the transforms
+    fn from_f64(value: f64) -> Self;
+}
 
-    while val <= 4 {
-        num = num | (num >> 2i32.pow(val));
-        val = val + 1;
+impl RealFftCoefficient for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value as f32
     }
+}
 
-    num = num + 1;
-    return num;
+impl RealFftCoefficient for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn from_f64(value: f64) -> Self {
+        value
+    }
 }
 
-/// Returns roots of unity contained in the upper trig circle
-fn get_roots_of_unity(size: usize) -> Vec<FftComplex> {
-    let half_size = size >> 1;
+/// The fft-based convolution of real inputs, accepting inputs of different sizes.
+///
+/// It computes the same product as linear_convolution_via_fft, but the real-input transforms exploit the Hermitian
+/// symmetry of the spectra: each transform has half the size of a complex one, which roughly halves the cost.
+pub fn linear_convolution_via_real_fft<T: RealFftCoefficient>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let output_size = a.len() + b.len() - 1;
+    let mut convolution = _convolution_via_real_fft(a, b, next_power_of_2(output_size));
+    convolution.truncate(output_size);
+    convolution
+}
 
-    let theta = 2.0 * std::f64::consts::PI / (size as f64);
-    let first_root = FftComplex::new(theta.cos(), theta.sin());
+/// The fft-based cyclic convolution of real inputs, where both inputs have the same size n and the output is the
+/// product modulo x^n - 1.
+///
+/// As for cyclic_convolution_via_fft, the transform is done at size n directly when n only has small prime factors.
+pub fn cyclic_convolution_via_real_fft<T: RealFftCoefficient>(a: &[T], b: &[T]) -> Vec<T> {
+    assert!(a.len() == b.len());
+    let size = a.len();
+    if size == 0 {
+        return vec![];
+    }
+    if factorize(size).into_iter().max().unwrap_or(1) > CYCLIC_FFT_MAX_FACTOR {
+        return fold_cyclic(linear_convolution_via_real_fft(a, b), size);
+    }
+    _convolution_via_real_fft(a, b, size)
+}
 
-    let mut roots = vec![FftComplex::from(1.0); half_size];
+/// Cyclic convolution of the inputs padded with zeros to the given size
+fn _convolution_via_real_fft<T: RealFftCoefficient>(a: &[T], b: &[T], size: usize) -> Vec<T> {
+    let plan = FftPlanner::global().real_plan(size);
+    let a_values: Vec<f64> = a.iter().map(|&x| x.to_f64()).collect();
+    let b_values: Vec<f64> = b.iter().map(|&x| x.to_f64()).collect();
+    let mut a_spectrum = plan.forward(&a_values);
+    let b_spectrum = plan.forward(&b_values);
 
-    for i in 1..half_size {
-        roots[i] = roots[i - 1] * first_root;
+    for (x, y) in a_spectrum.iter_mut().zip(b_spectrum.iter()) {
+        *x *= *y;
     }
 
-    return roots;
+    plan.inverse(&a_spectrum)
+        .into_iter()
+        .map(T::from_f64)
+        .collect()
 }
 
-fn _fft_forward(a: &[FftComplex], roots: &[FftComplex], dst: &mut Vec<FftComplex>) {
-    _fft(a, &roots, dst);
-}
-fn _fft_backward(a: &[FftComplex], roots: &mut [FftComplex], dst: &mut Vec<FftComplex>) {
-    // We must change the sign of the imaginary part of roots to define them of the bottom circle
-    for val in roots.into_iter() {
-        *val = FftComplex::new(val.real(), -val.imag());
+fn _convolution_via_fft(mut a: Vec<FftComplex>, mut b: Vec<FftComplex>) -> Vec<FftComplex> {
+    if a.is_empty() {
+        return vec![];
     }
 
-    // Compute backward fft
-    _fft(a, &roots, dst);
+    let plan = FftPlanner::global().plan(a.len());
+    plan.forward(&mut a);
+    plan.forward(&mut b);
 
-    // Apply scaling
-    let size_f = a.len() as f64;
-    let size_inverse = FftComplex::from(1.0 / size_f);
-    for coef in dst {
-        *coef *= size_inverse;
+    // The term by term product in Fourier space is equivalent to the convolution in the vector space
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x *= *y;
     }
+
+    plan.inverse(&mut a);
+    a
 }
 
-fn _fft(a: &[FftComplex], roots: &[FftComplex], dst: &mut [FftComplex]) {
-    let size = a.len();
+fn next_power_of_2(mut num: usize) -> usize {
+    let mut val: u32 = 0;
 
-    // Quick exit
-    if size == 1 {
-        dst[0] = a[0];
-        return;
-    } else if size == 2 {
-        dst[0] = a[0] + a[1];
-        dst[1] = a[0] - a[1];
-    } else if size == 4 {
-        dst[0] = a[0] + a[1] + a[2] + a[3];
-        dst[2] = a[0] + a[1] - a[2] - a[3];
-        dst[1] = a[0] - a[1] + roots[1] * (a[2] - a[3]);
-        dst[3] = a[0] - a[1] - roots[1] * (a[2] - a[3]);
-    }
-
-    // Size must be a power of 2 every step of the way
-    if (size & (size - 1)) != 0 {
-        panic!("FFT: size is not a power of 2");
-    }
-
-    for i in (0..size).step_by(4) {
-        dst[i] = a[i] + a[i + 1] + a[i + 2] + a[i + 3];
-        dst[i + 2] = a[i] + a[i + 1] - a[i + 2] - a[i + 3];
-        dst[i + 1] = a[i] - a[i + 1] + roots[roots.len() >> 1] * (a[i + 2] - a[i + 3]);
-        dst[i + 3] = a[i] - a[i + 1] - roots[roots.len() >> 1] * (a[i + 2] - a[i + 3]);
-    }
-
-    let mut step = 8;
-    let mut half_step = step >> 1;
-    let mut roots_stride = size >> 3;
-    while step <= size {
-        for sub_fft in dst.chunks_mut(step) {
-            for i in 0..half_step {
-                let odd_term = roots[i * roots_stride] * sub_fft[half_step + i];
-                sub_fft[i + half_step] = sub_fft[i] - odd_term;
-                sub_fft[i] += odd_term;
-            }
-        }
-        half_step <<= 1;
-        step <<= 1;
-        roots_stride >>= 1;
-    }
-}
+    num = num - 1;
 
-fn oddeven_sort(size: usize, indices: &mut [usize]) {
-    // Size must be a power of 2 every step of the way
-    if (size & (size - 1)) != 0 || size == 0 {
-        panic!();
+    while val <= 4 {
+        num = num | (num >> 2i32.pow(val));
+        val = val + 1;
     }
-    let shift = usize::BITS - size.ilog2();
-    indices
-        .iter_mut()
-        .enumerate()
-        .for_each(|(i, n)| *n = i.reverse_bits() >> shift);
+
+    num = num + 1;
+    return num;
 }
@@ -0,0 +1,177 @@
+//! This module implements the euclidean division of polynomials
+//!
+//! For low degrees, this is the classic school book long division, with complexity O(n*m).
+//! For higher degrees, the quotient is obtained from the reciprocal of the reversed divisor, computed via Newton
+//! iteration on top of the fft-based convolution, so that the whole division costs O(nlog(n)).
+
+use crate::algebra::Field;
+use crate::polynomial::convolution::{linear_convolution, FastConvolution};
+use crate::polynomial::{Polynomial, PolynomialError, PolynomialResult};
+
+use std::ops::{Div, Rem};
+
+/// Below this size for both the divisor and the quotient, the long division is faster than Newton iteration
+const NEWTON_DIVISION_THRESHOLD: usize = 130;
+
+impl<T> Polynomial<T>
+where
+    T: FastConvolution + Field,
+{
+    /// Euclidean division: returns the quotient q and the remainder r such that self = q * divisor + r,
+    /// with deg(r) < deg(divisor)
+    pub fn div_rem(
+        &self,
+        divisor: &Polynomial<T>,
+    ) -> Result<(Polynomial<T>, Polynomial<T>), PolynomialError> {
+        let mut dividend = self.clone();
+        dividend.trim();
+        let mut divisor = divisor.clone();
+        divisor.trim();
+
+        if divisor.coefs.is_empty() {
+            return Err(PolynomialError::DivisionByZero(
+                "Polynomial division by the zero polynomial".to_string(),
+            ));
+        }
+        if dividend.coefs.len() < divisor.coefs.len() {
+            return Ok((Polynomial::new(&[]), dividend));
+        }
+
+        let divisor_len = divisor.coefs.len();
+        let quotient_len = dividend.coefs.len() - divisor_len + 1;
+        let (mut quotient, mut remainder) = if divisor_len > NEWTON_DIVISION_THRESHOLD
+            && quotient_len > NEWTON_DIVISION_THRESHOLD
+        {
+            Self::fast_div_rem(&dividend, &divisor)
+        } else {
+            Self::long_div_rem(&dividend, &divisor)
+        };
+
+        quotient.trim();
+        remainder.trim();
+        Ok((quotient, remainder))
+    }
+
+    /// School book long division: both inputs must be trimmed, and the dividend must have a higher degree
+    pub(super) fn long_div_rem(
+        dividend: &Polynomial<T>,
+        divisor: &Polynomial<T>,
+    ) -> (Polynomial<T>, Polynomial<T>) {
+        let divisor_len = divisor.coefs.len();
+        let quotient_len = dividend.coefs.len() - divisor_len + 1;
+        let lead = divisor.coefs[divisor_len - 1];
+
+        let mut remainder = dividend.coefs.clone();
+        let mut quotient = vec![T::zero(); quotient_len];
+        for i in (0..quotient_len).rev() {
+            let q = remainder[i + divisor_len - 1] / lead;
+            for j in 0..divisor_len {
+                remainder[i + j] -= q * divisor.coefs[j];
+            }
+            quotient[i] = q;
+        }
+        remainder.truncate(divisor_len - 1);
+
+        (
+            Polynomial { coefs: quotient },
+            Polynomial { coefs: remainder },
+        )
+    }
+
+    /// Fast division: the reversed quotient is the product of the reversed dividend with the
+    /// reciprocal of the reversed divisor, as power series truncated to the quotient size.
+    /// Both inputs must be trimmed, and the dividend must have a higher degree.
+    pub(super) fn fast_div_rem(
+        dividend: &Polynomial<T>,
+        divisor: &Polynomial<T>,
+    ) -> (Polynomial<T>, Polynomial<T>) {
+        let divisor_len = divisor.coefs.len();
+        let quotient_len = dividend.coefs.len() - divisor_len + 1;
+
+        let rev_dividend: Vec<T> = dividend.coefs.iter().rev().copied().collect();
+        let rev_divisor: Vec<T> = divisor.coefs.iter().rev().copied().collect();
+
+        let reciprocal = Self::reciprocal(&rev_divisor, quotient_len);
+        let mut quotient = truncated_product(&rev_dividend, &reciprocal, quotient_len);
+        quotient.reverse();
+
+        // Only the coefficients below the degree of the divisor survive in the remainder
+        let product = truncated_product(&divisor.coefs, &quotient, divisor_len - 1);
+        let remainder = dividend
+            .coefs
+            .iter()
+            .zip(product.iter())
+            .map(|(&a, &b)| a - b)
+            .collect();
+
+        (
+            Polynomial { coefs: quotient },
+            Polynomial { coefs: remainder },
+        )
+    }
+
+    /// Computes the inverse of a power series modulo x^len via Newton iteration:
+    /// g <- g * (2 - f * g), which doubles the number of correct coefficients at each step.
+    /// The first coefficient of the series must be non-zero.
+    pub(super) fn reciprocal(series: &[T], len: usize) -> Vec<T> {
+        let mut inverse = vec![T::one() / series[0]];
+        let mut precision = 1;
+
+        while precision < len {
+            precision = usize::min(2 * precision, len);
+
+            let truncated_series = &series[..usize::min(precision, series.len())];
+            let mut correction = truncated_product(truncated_series, &inverse, precision);
+            for coef in correction.iter_mut() {
+                *coef = -*coef;
+            }
+            correction[0] += T::from_integer(2);
+
+            inverse = truncated_product(&inverse, &correction, precision);
+        }
+
+        inverse
+    }
+}
+
+/// Product of two coefficients slices of any length, truncated to its first len coefficients.
+/// The output always has exactly len coefficients.
+pub(super) fn truncated_product<T>(a: &[T], b: &[T], len: usize) -> Vec<T>
+where
+    T: FastConvolution,
+{
+    let a = &a[..usize::min(a.len(), len)];
+    let b = &b[..usize::min(b.len(), len)];
+
+    let mut product = linear_convolution(a, b);
+    product.resize(len, T::zero());
+    product
+}
+
+/// The Div operation for polynomials references: returns the quotient of the euclidean division.
+///
+/// This returns a Result because the divisor could be the zero polynomial.
+impl<'a, T> Div for &'a Polynomial<T>
+where
+    T: FastConvolution + Field,
+{
+    type Output = PolynomialResult<T>;
+
+    fn div(self, other: &'a Polynomial<T>) -> PolynomialResult<T> {
+        Ok(self.div_rem(other)?.0)
+    }
+}
+
+/// The Rem operation for polynomials references: returns the remainder of the euclidean division.
+///
+/// This returns a Result because the divisor could be the zero polynomial.
+impl<'a, T> Rem for &'a Polynomial<T>
+where
+    T: FastConvolution + Field,
+{
+    type Output = PolynomialResult<T>;
+
+    fn rem(self, other: &'a Polynomial<T>) -> PolynomialResult<T> {
+        Ok(self.div_rem(other)?.1)
+    }
+}
@@ -0,0 +1,320 @@
+//! This module implements the conversion of modular polynomials to and from their point-value representation.
+//!
+//! A polynomial mod(x^n - 1) is determined by its values at the n n-th roots of unity, which are the roots of
+//! x^n - 1: the forward transform evaluates it there, and the inverse transform interpolates it back. The product of
+//! two polynomials in the ring is then the pointwise product of their values. A polynomial mod(x^n + 1) is evaluated
+//! at the roots of x^n + 1 instead, which are psi * w^k where psi is a root of unity of order 2n and w = psi^2: its
+//! coefficients are twisted by the powers of psi before the transform, and untwisted after the inverse one.
+//!
+//! Floating point types (real or complex) are evaluated with the fft, in double precision complex numbers. Prime
+//! fields are evaluated with the ntt, which needs a power of 2 modulus and a root of unity of the right order.
+//!
+//! EvaluationForm keeps a polynomial in point-value representation, where products are pointwise: a polynomial
+//! multiplied many times only needs to be transformed once.
+
+use crate::algebra::CommutativeRing;
+use crate::complex;
+use crate::polynomial::fft::FftPlanner;
+use crate::polynomial::ntt::ntt;
+use crate::polynomial::{
+    check_ring, ModularArithmeticError, ModularArithmeticPolynomial, Polynomial, RingKind,
+};
+use crate::prime_field::Zp;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+type FftComplex = complex::Complex<f64>;
+
+/// Coefficient types in which modular polynomials can be evaluated at the roots of their modulus
+pub trait EvaluationDomain: CommutativeRing {
+    /// Type of the values of the polynomials
+    type Value: CommutativeRing;
+
+    /// Values of the polynomial with these coefficients at the roots of x^n - 1 (or x^n + 1 in a negacyclic
+    /// ring), where n is the number of coefficients. None if the type has no such roots for this size.
+    fn evaluate(coefs: &[Self], kind: RingKind) -> Option<Vec<Self::Value>>;
+
+    /// Coefficients of the polynomial of degree lower than n taking the n values, inverse of evaluate.
+    ///
+    /// # Panics
+    /// Panics if evaluate isn't supported at this size.
+    fn interpolate(values: &[Self::Value], kind: RingKind) -> Vec<Self>;
+}
+
+/// Implements EvaluationDomain for a floating point coefficient type, with the fft
+macro_rules! impl_fft_evaluation_domain {
+    ($coefficient:ty) => {
+        impl EvaluationDomain for $coefficient {
+            type Value = FftComplex;
+
+            fn evaluate(coefs: &[Self], kind: RingKind) -> Option<Vec<FftComplex>> {
+                let mut values: Vec<FftComplex> =
+                    coefs.iter().map(|&x| FftComplex::from(x)).collect();
+                if values.is_empty() {
+                    return Some(values);
+                }
+                if kind == RingKind::Negacyclic {
+                    twist(&mut values, -1.0);
+                }
+                FftPlanner::global().plan(values.len()).forward(&mut values);
+                Some(values)
+            }
+
+            fn interpolate(values: &[FftComplex], kind: RingKind) -> Vec<Self> {
+                let mut coefs = values.to_vec();
+                if coefs.is_empty() {
+                    return vec![];
+                }
+                FftPlanner::global().plan(coefs.len()).inverse(&mut coefs);
+                if kind == RingKind::Negacyclic {
+                    twist(&mut coefs, 1.0);
+                }
+                coefs.into_iter().map(Self::from).collect()
+            }
+        }
+    };
+}
+
+impl_fft_evaluation_domain!(f32);
+impl_fft_evaluation_domain!(f64);
+impl_fft_evaluation_domain!(complex::Complex<f32>);
+impl_fft_evaluation_domain!(complex::Complex<f64>);
+
+/// Multiplies the values by the powers of psi = e^(sign i pi / n), a root of unity of order 2n
+fn twist(values: &mut [FftComplex], sign: f64) {
+    let step = sign * std::f64::consts::PI / values.len() as f64;
+    for (j, x) in values.iter_mut().enumerate() {
+        *x *= FftComplex::from_polar(1.0, step * j as f64);
+    }
+}
+
+/// Prime fields use the ntt, for power of 2 moduli with a root of unity of the right order
+impl<const P: u64> EvaluationDomain for Zp<P> {
+    type Value = Self;
+
+    fn evaluate(coefs: &[Self], kind: RingKind) -> Option<Vec<Self>> {
+        let mut values = coefs.to_vec();
+        if values.is_empty() {
+            return Some(values);
+        }
+        let (root, psi) = ntt_roots::<P>(values.len(), kind)?;
+        if let Some(psi) = psi {
+            twist_zp(&mut values, psi);
+        }
+        ntt(&mut values, root);
+        Some(values)
+    }
+
+    fn interpolate(values: &[Self], kind: RingKind) -> Vec<Self> {
+        let mut coefs = values.to_vec();
+        if coefs.is_empty() {
+            return coefs;
+        }
+        let (root, psi) = ntt_roots::<P>(coefs.len(), kind)
+            .expect("Interpolation at a size which can't be evaluated");
+
+        // The backward transform is the forward one with the inverse root, followed by a division by the size
+        ntt(
+            &mut coefs,
+            root.inverse().expect("Roots of unity are non-zero"),
+        );
+        let size_inverse = Zp::<P>::new(coefs.len() as u64)
+            .inverse()
+            .expect("Sizes of transforms are smaller than P");
+        for x in coefs.iter_mut() {
+            *x *= size_inverse;
+        }
+        if let Some(psi) = psi {
+            twist_zp(
+                &mut coefs,
+                psi.inverse().expect("Roots of unity are non-zero"),
+            );
+        }
+        coefs
+    }
+}
+
+/// Root of unity of order size used by the ntt, and for a negacyclic ring the root psi of order 2 * size whose
+/// square it is. None if the size isn't a power of 2 or the field has no such root.
+fn ntt_roots<const P: u64>(size: usize, kind: RingKind) -> Option<(Zp<P>, Option<Zp<P>>)> {
+    if !size.is_power_of_two() {
+        return None;
+    }
+    let log_size = size.trailing_zeros();
+    match kind {
+        RingKind::Cyclic => Some((Zp::<P>::root_of_unity(log_size)?, None)),
+        RingKind::Negacyclic => {
+            let psi = Zp::<P>::root_of_unity(log_size + 1)?;
+            Some((psi * psi, Some(psi)))
+        }
+    }
+}
+
+/// Multiplies the values by the powers of psi
+fn twist_zp<const P: u64>(values: &mut [Zp<P>], psi: Zp<P>) {
+    let mut power = Zp::<P>::new(1);
+    for x in values.iter_mut() {
+        *x *= power;
+        power *= psi;
+    }
+}
+
+impl<T: EvaluationDomain> ModularArithmeticPolynomial<T> {
+    /// Values of the polynomial at the roots of its modulus (x^modulus - 1, or x^modulus + 1 in a negacyclic ring),
+    /// in the order of the forward transform. Returns an error if the coefficient type has no such roots.
+    pub fn evaluate(&self) -> Result<Vec<T::Value>, ModularArithmeticError> {
+        T::evaluate(&self.polynomial.coefs, self.kind).ok_or_else(|| {
+            ModularArithmeticError::NoEvaluationDomain(format!(
+                "No evaluation at the roots of the modulus {} for this coefficient type",
+                self.modulus()
+            ))
+        })
+    }
+
+    /// Polynomial taking the given values at the roots of its modulus, which is the number of values.
+    /// This is the inverse of evaluate.
+    ///
+    /// # Panics
+    /// Panics if the coefficient type has no roots for this modulus.
+    pub fn interpolate(values: &[T::Value], kind: RingKind) -> Self {
+        Self {
+            polynomial: Polynomial::new(&T::interpolate(values, kind)),
+            kind,
+        }
+    }
+}
+
+/// A modular polynomial in point-value representation: its values at the roots of its modulus.
+///
+/// The conversions to and from ModularArithmeticPolynomial are exact in a prime field, and exact up to rounding
+/// errors for floating point types. Additions, subtractions and products are all pointwise, in O(n).
+#[derive(Clone, Debug)]
+pub struct EvaluationForm<T: EvaluationDomain> {
+    values: Vec<T::Value>,
+    kind: RingKind,
+}
+
+/// Result type of the operations which can fail on a mismatch of moduli
+type EvaluationFormResult<T> = Result<EvaluationForm<T>, ModularArithmeticError>;
+
+impl<T: EvaluationDomain> EvaluationForm<T> {
+    /// Converts a polynomial, which fails if its coefficient type has no roots for its modulus
+    pub fn new(poly: &ModularArithmeticPolynomial<T>) -> EvaluationFormResult<T> {
+        Ok(Self {
+            values: poly.evaluate()?,
+            kind: poly.kind(),
+        })
+    }
+
+    /// Converts back to coefficients
+    pub fn to_polynomial(&self) -> ModularArithmeticPolynomial<T> {
+        ModularArithmeticPolynomial::interpolate(&self.values, self.kind)
+    }
+
+    /// Values of the polynomial, in the order of the forward transform
+    pub fn values(&self) -> &[T::Value] {
+        &self.values
+    }
+
+    pub fn modulus(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Public getter for the kind of ring
+    pub fn kind(&self) -> RingKind {
+        self.kind
+    }
+
+    /// Check the modulus of another polynomial against this one
+    fn check_modulus(&self, other: (usize, RingKind)) -> Result<(), ModularArithmeticError> {
+        check_ring((self.modulus(), self.kind), other)
+    }
+
+    /// Pointwise combination of the values of two polynomials of the same ring
+    fn zip_with(
+        &self,
+        other: &EvaluationForm<T>,
+        f: impl Fn(T::Value, T::Value) -> T::Value,
+    ) -> EvaluationFormResult<T> {
+        self.check_modulus((other.modulus(), other.kind))?;
+        Ok(Self {
+            values: self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect(),
+            kind: self.kind,
+        })
+    }
+}
+
+impl<T: EvaluationDomain> TryFrom<&ModularArithmeticPolynomial<T>> for EvaluationForm<T> {
+    type Error = ModularArithmeticError;
+
+    fn try_from(poly: &ModularArithmeticPolynomial<T>) -> EvaluationFormResult<T> {
+        Self::new(poly)
+    }
+}
+
+impl<T: EvaluationDomain> From<&EvaluationForm<T>> for ModularArithmeticPolynomial<T> {
+    fn from(form: &EvaluationForm<T>) -> Self {
+        form.to_polynomial()
+    }
+}
+
+/// The Add operation for polynomials in point-value representation, which returns a Result because there
+/// potentially could be a mismatch of moduli between the two polynomials.
+impl<'a, T: EvaluationDomain> Add for &'a EvaluationForm<T> {
+    type Output = EvaluationFormResult<T>;
+
+    fn add(self, other: &'a EvaluationForm<T>) -> EvaluationFormResult<T> {
+        self.zip_with(other, |x, y| x + y)
+    }
+}
+
+/// The Sub operation for polynomials in point-value representation
+impl<'a, T: EvaluationDomain> Sub for &'a EvaluationForm<T> {
+    type Output = EvaluationFormResult<T>;
+
+    fn sub(self, other: &'a EvaluationForm<T>) -> EvaluationFormResult<T> {
+        self.zip_with(other, |x, y| x - y)
+    }
+}
+
+/// The Neg operation for polynomials in point-value representation
+impl<T: EvaluationDomain> Neg for &EvaluationForm<T> {
+    type Output = EvaluationForm<T>;
+
+    fn neg(self) -> EvaluationForm<T> {
+        EvaluationForm {
+            values: self.values.iter().map(|&x| -x).collect(),
+            kind: self.kind,
+        }
+    }
+}
+
+/// The Mul operation for polynomials in point-value representation: the product in the ring is the pointwise
+/// product of the values, in O(n).
+impl<'a, T: EvaluationDomain> Mul for &'a EvaluationForm<T> {
+    type Output = EvaluationFormResult<T>;
+
+    fn mul(self, other: &'a EvaluationForm<T>) -> EvaluationFormResult<T> {
+        self.zip_with(other, |x, y| x * y)
+    }
+}
+
+/// The Mul operation by a polynomial in coefficient representation, whose result is in coefficient representation
+/// as well. It only transforms the other polynomial and interpolates the product, which saves one of the three
+/// transforms of a product of two polynomials.
+impl<'a, T: EvaluationDomain> Mul<&'a ModularArithmeticPolynomial<T>> for &'a EvaluationForm<T> {
+    type Output = Result<ModularArithmeticPolynomial<T>, ModularArithmeticError>;
+
+    fn mul(
+        self,
+        other: &'a ModularArithmeticPolynomial<T>,
+    ) -> Result<ModularArithmeticPolynomial<T>, ModularArithmeticError> {
+        self.check_modulus((other.modulus(), other.kind()))?;
+        Ok((self * &EvaluationForm::new(other)?)?.to_polynomial())
+    }
+}
@@ -0,0 +1,596 @@
+//! This module implements reusable fft plans, for any size, and the discrete Fourier transform built on them.
+//!
+//! A plan holds everything that only depends on the size of the transform: the roots of unity (twiddle factors),
+//! and the bit reversal permutation or the factorization of the size. Creating it once and reusing it for many
+//! transforms avoids recomputing them at each call. Powers of 2 use a radix 2 algorithm, smooth sizes a mixed radix
+//! Cooley-Tukey algorithm, and sizes with a large prime factor Bluestein's algorithm, which rewrites the transform
+//! as a convolution computed with a power of 2 fft. Plans are immutable, and can be shared between threads; the
+//! planner keeps a cache of plans keyed by size, so that all the fft-based convolutions of a given size share the
+//! same plan.
+
+use crate::complex;
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+type FftComplex = complex::Complex<f64>;
+
+/// Precomputed data for the fft of a given size.
+///
+/// The forward transform is X_k = sum x_n e^(-2i pi nk / size), and the inverse transform includes the division
+/// by the size, so that inverse(forward(x)) = x.
+pub struct FftPlan {
+    size: usize,
+    algorithm: Algorithm,
+}
+
+/// Algorithm used by a plan, depending on its size
+enum Algorithm {
+    /// Iterative radix 2 transform, for powers of 2
+    Radix2 {
+        /// e^(-2i pi k / size) for k < size / 2
+        roots: Vec<FftComplex>,
+        /// Conjugates of the roots, used by the inverse transform
+        inverse_roots: Vec<FftComplex>,
+        /// bit_reversal[i] is i with its log2(size) bits reversed
+        bit_reversal: Vec<usize>,
+    },
+    /// Recursive mixed radix Cooley-Tukey transform, for sizes without large prime factors
+    MixedRadix {
+        /// Prime factors of the size (with 4 used instead of 2 * 2), one per recursion level
+        factors: Vec<usize>,
+        /// e^(-2i pi k / size) for k < size
+        twiddles: Vec<FftComplex>,
+    },
+    /// Bluestein's chirp-z transform, for sizes with a large prime factor
+    Bluestein {
+        /// e^(-i pi k^2 / size) for k < size
+        chirp: Vec<FftComplex>,
+        /// Forward transform of the conjugated chirp, wrapped around to the size of the inner plan
+        filter: Vec<FftComplex>,
+        /// Power of 2 plan, of size at least 2 * size - 1
+        inner: Box<FftPlan>,
+    },
+}
+
+/// Above this prime factor in the size, Bluestein's algorithm is faster than the mixed radix one, whose butterflies
+/// have a cost proportional to the factor
+const BLUESTEIN_THRESHOLD: usize = 32;
+
+/// e^(-2i pi k / size) for k < count. Each root is computed directly from its angle, so that errors don't accumulate.
+fn roots_of_unity(size: usize, count: usize) -> Vec<FftComplex> {
+    let theta = -2.0 * std::f64::consts::PI / (size as f64);
+    (0..count)
+        .map(|k| FftComplex::from_polar(1.0, theta * k as f64))
+        .collect()
+}
+
+/// Factors of the size, in the order used by the mixed radix recursion
+pub(crate) fn factorize(mut size: usize) -> Vec<usize> {
+    let mut factors = vec![];
+    while size.is_multiple_of(4) {
+        factors.push(4);
+        size /= 4;
+    }
+    let mut factor = 2;
+    while size > 1 {
+        if factor * factor > size {
+            factors.push(size);
+            break;
+        }
+        while size.is_multiple_of(factor) {
+            factors.push(factor);
+            size /= factor;
+        }
+        factor += if factor == 2 { 1 } else { 2 };
+    }
+    factors
+}
+
+impl FftPlan {
+    /// Precomputes the twiddle factors, and the bit reversal permutation or the factorization of the size.
+    ///
+    /// Powers of 2 use a radix 2 algorithm, and smooth sizes like 3 * 2^k or 10^k a mixed radix algorithm. Sizes
+    /// with a prime factor above 32 use Bluestein's algorithm, so that any size is computed in O(size log(size)).
+    ///
+    /// # Panics
+    /// Panics if the size is zero.
+    pub fn new(size: usize) -> Self {
+        if size == 0 {
+            panic!("FFT: size must be non-zero");
+        }
+
+        let algorithm = if size.is_power_of_two() {
+            let roots = roots_of_unity(size, size / 2);
+            let inverse_roots = roots.iter().map(|root| root.conj()).collect();
+            let bit_reversal = match size.ilog2() {
+                0 => vec![0],
+                log_size => (0..size)
+                    .map(|i| i.reverse_bits() >> (usize::BITS - log_size))
+                    .collect(),
+            };
+            Algorithm::Radix2 {
+                roots,
+                inverse_roots,
+                bit_reversal,
+            }
+        } else {
+            let factors = factorize(size);
+            if factors.iter().any(|&factor| factor > BLUESTEIN_THRESHOLD) {
+                Self::bluestein(size)
+            } else {
+                Algorithm::MixedRadix {
+                    factors,
+                    twiddles: roots_of_unity(size, size),
+                }
+            }
+        };
+
+        Self { size, algorithm }
+    }
+
+    /// With nk = (n^2 + k^2 - (k - n)^2) / 2, the transform becomes X_k = w_k sum_n (x_n w_n) conj(w_(k-n)), where
+    /// w_k = e^(-i pi k^2 / size) is the chirp: a convolution, which can be computed by a power of 2 fft.
+    fn bluestein(size: usize) -> Algorithm {
+        // k^2 is reduced modulo 2 * size, the period of the chirp, to keep the angles accurate
+        let theta = -std::f64::consts::PI / (size as f64);
+        let chirp: Vec<FftComplex> = (0..size)
+            .map(|k| FftComplex::from_polar(1.0, theta * ((k * k) % (2 * size)) as f64))
+            .collect();
+
+        let inner = FftPlan::new((2 * size - 1).next_power_of_two());
+        let mut filter = vec![FftComplex::new(0.0, 0.0); inner.size()];
+        filter[0] = chirp[0].conj();
+        for k in 1..size {
+            filter[k] = chirp[k].conj();
+            filter[inner.size() - k] = chirp[k].conj();
+        }
+        inner.forward(&mut filter);
+
+        Algorithm::Bluestein {
+            chirp,
+            filter,
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Size of the transforms computed by the plan
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// In place forward transform
+    ///
+    /// # Panics
+    /// Panics if the data doesn't have the size of the plan.
+    pub fn forward(&self, data: &mut [FftComplex]) {
+        self.check_size(data);
+        match &self.algorithm {
+            Algorithm::Radix2 {
+                roots,
+                bit_reversal,
+                ..
+            } => radix_2(data, roots, bit_reversal),
+            Algorithm::MixedRadix { factors, twiddles } => {
+                let input = data.to_vec();
+                mixed_radix(&input, 0, 1, data, factors, twiddles);
+            }
+            Algorithm::Bluestein {
+                chirp,
+                filter,
+                inner,
+            } => {
+                let mut buffer = vec![FftComplex::new(0.0, 0.0); inner.size()];
+                for ((b, &x), &w) in buffer.iter_mut().zip(data.iter()).zip(chirp.iter()) {
+                    *b = x * w;
+                }
+                inner.forward(&mut buffer);
+                for (b, &f) in buffer.iter_mut().zip(filter.iter()) {
+                    *b *= f;
+                }
+                inner.inverse(&mut buffer);
+                for ((x, &b), &w) in data.iter_mut().zip(buffer.iter()).zip(chirp.iter()) {
+                    *x = b * w;
+                }
+            }
+        }
+    }
+
+    /// In place inverse transform, including the division by the size
+    ///
+    /// # Panics
+    /// Panics if the data doesn't have the size of the plan.
+    pub fn inverse(&self, data: &mut [FftComplex]) {
+        self.check_size(data);
+        let size_inverse = 1.0 / self.size as f64;
+        match &self.algorithm {
+            Algorithm::Radix2 {
+                inverse_roots,
+                bit_reversal,
+                ..
+            } => {
+                radix_2(data, inverse_roots, bit_reversal);
+                for x in data.iter_mut() {
+                    *x = FftComplex::new(x.real() * size_inverse, x.imag() * size_inverse);
+                }
+            }
+            Algorithm::MixedRadix { .. } | Algorithm::Bluestein { .. } => {
+                // The inverse transform is conj(forward(conj(x))) / size
+                for x in data.iter_mut() {
+                    *x = x.conj();
+                }
+                self.forward(data);
+                for x in data.iter_mut() {
+                    *x = FftComplex::new(x.real() * size_inverse, -x.imag() * size_inverse);
+                }
+            }
+        }
+    }
+
+    fn check_size(&self, data: &[FftComplex]) {
+        if data.len() != self.size {
+            panic!(
+                "FFT: plan of size {} used on data of size {}",
+                self.size,
+                data.len()
+            );
+        }
+    }
+}
+
+/// Above this size, the butterflies of a transform are spread over several threads with the parallel feature
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 14;
+/// Minimum number of butterflies computed by a thread at once
+#[cfg(feature = "parallel")]
+const PARALLEL_GRAIN: usize = 1 << 12;
+
+/// Iterative radix 2 transform: values are sorted in bit reversed order, then merged with butterflies of
+/// increasing size
+fn radix_2(data: &mut [FftComplex], roots: &[FftComplex], bit_reversal: &[usize]) {
+    let size = data.len();
+    for (i, &j) in bit_reversal.iter().enumerate() {
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut half = 1;
+    while half < size {
+        let stride = size / (2 * half);
+        #[cfg(feature = "parallel")]
+        if size >= PARALLEL_THRESHOLD {
+            parallel_butterflies(data, half, roots, stride);
+            half *= 2;
+            continue;
+        }
+        for block in data.chunks_exact_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+            butterflies(low, high, 0, roots, stride);
+        }
+        half *= 2;
+    }
+}
+
+/// Butterflies between the low and high halves of a block, where the first pair has index first in the block
+fn butterflies(
+    low: &mut [FftComplex],
+    high: &mut [FftComplex],
+    first: usize,
+    roots: &[FftComplex],
+    stride: usize,
+) {
+    for (k, (l, h)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+        let product = roots[(first + k) * stride] * *h;
+        *h = *l - product;
+        *l += product;
+    }
+}
+
+/// One pass of radix 2 butterflies, where both the blocks and the butterflies of large blocks are split between
+/// threads. Each butterfly is computed the same way as in the sequential pass.
+#[cfg(feature = "parallel")]
+fn parallel_butterflies(data: &mut [FftComplex], half: usize, roots: &[FftComplex], stride: usize) {
+    use rayon::prelude::*;
+    data.par_chunks_exact_mut(2 * half)
+        .with_min_len(usize::max(1, PARALLEL_GRAIN / half))
+        .for_each(|block| {
+            let (low, high) = block.split_at_mut(half);
+            low.par_chunks_mut(PARALLEL_GRAIN)
+                .zip(high.par_chunks_mut(PARALLEL_GRAIN))
+                .enumerate()
+                .for_each(|(n, (low, high))| {
+                    butterflies(low, high, n * PARALLEL_GRAIN, roots, stride)
+                });
+        });
+}
+
+/// Recursive decimation in time: the transform of the input values input[offset + n * stride] is written in output.
+///
+/// With a first factor p and m = size / p, the p sub-sequences x_(pn + j) are transformed recursively into Y_j,
+/// then X_(k + qm) = sum_j e^(-2i pi jk / size) Y_j[k] e^(-2i pi jq / p) is a DFT of size p for each k.
+fn mixed_radix(
+    input: &[FftComplex],
+    offset: usize,
+    stride: usize,
+    output: &mut [FftComplex],
+    factors: &[usize],
+    twiddles: &[FftComplex],
+) {
+    let (radix, sub_factors) = match factors.split_first() {
+        Some((&radix, sub_factors)) => (radix, sub_factors),
+        None => {
+            output[0] = input[offset];
+            return;
+        }
+    };
+    let size = output.len();
+    let sub_size = size / radix;
+    let sub_transform = |(j, sub_output): (usize, &mut [FftComplex])| {
+        mixed_radix(
+            input,
+            offset + j * stride,
+            stride * radix,
+            sub_output,
+            sub_factors,
+            twiddles,
+        )
+    };
+    #[cfg(feature = "parallel")]
+    if size >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        output
+            .par_chunks_exact_mut(sub_size)
+            .enumerate()
+            .for_each(sub_transform);
+    } else {
+        output
+            .chunks_exact_mut(sub_size)
+            .enumerate()
+            .for_each(sub_transform);
+    }
+    #[cfg(not(feature = "parallel"))]
+    output
+        .chunks_exact_mut(sub_size)
+        .enumerate()
+        .for_each(sub_transform);
+
+    // Twiddles are roots of unity of order twiddles.len(), a multiple of size
+    let twiddle_step = twiddles.len() / size;
+    let radix_step = twiddles.len() / radix;
+    let mut scratch = vec![FftComplex::new(0.0, 0.0); radix];
+    for k in 0..sub_size {
+        for (j, value) in scratch.iter_mut().enumerate() {
+            *value = output[j * sub_size + k] * twiddles[j * k * twiddle_step];
+        }
+        for q in 0..radix {
+            let mut sum = scratch[0];
+            for (j, &value) in scratch.iter().enumerate().skip(1) {
+                sum += value * twiddles[(j * q % radix) * radix_step];
+            }
+            output[q * sub_size + k] = sum;
+        }
+    }
+}
+
+/// Precomputed data for the fft of real signals of a given size.
+///
+/// The transform of a real signal has the Hermitian symmetry X_(size-k) = conj(X_k). For even sizes, this is used to
+/// compute it with a complex transform of half the size, whose input packs the even and odd values as real and
+/// imaginary parts. Odd sizes use a complex transform of the full size.
+pub struct RealFftPlan {
+    size: usize,
+    /// Complex plan of half the size for even sizes, or of the full size for odd sizes
+    inner: Arc<FftPlan>,
+    /// e^(-2i pi k / size) for k < size / 2, for even sizes
+    twiddles: Vec<FftComplex>,
+}
+
+impl RealFftPlan {
+    /// Precomputes the twiddle factors and the inner complex plan
+    ///
+    /// # Panics
+    /// Panics if the size is zero.
+    pub fn new(size: usize) -> Self {
+        if size == 0 {
+            panic!("FFT: size must be non-zero");
+        }
+        let inner_size = if size.is_multiple_of(2) {
+            size / 2
+        } else {
+            size
+        };
+        Self::with_inner(size, Arc::new(FftPlan::new(inner_size)))
+    }
+
+    fn with_inner(size: usize, inner: Arc<FftPlan>) -> Self {
+        let twiddles = if size.is_multiple_of(2) {
+            roots_of_unity(size, size / 2)
+        } else {
+            vec![]
+        };
+        Self {
+            size,
+            inner,
+            twiddles,
+        }
+    }
+
+    /// Size of the transforms computed by the plan
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Forward transform of a real signal, which is padded with zeros up to the size of the plan. All the size
+    /// values of the transform are returned, although the upper half is the conjugate of the lower one.
+    ///
+    /// # Panics
+    /// Panics if the signal is longer than the plan.
+    pub fn forward(&self, signal: &[f64]) -> Vec<FftComplex> {
+        if signal.len() > self.size {
+            panic!(
+                "FFT: real plan of size {} used on data of size {}",
+                self.size,
+                signal.len()
+            );
+        }
+        if self.twiddles.is_empty() {
+            let mut data: Vec<FftComplex> =
+                signal.iter().map(|&x| FftComplex::new(x, 0.0)).collect();
+            data.resize(self.size, FftComplex::new(0.0, 0.0));
+            self.inner.forward(&mut data);
+            return data;
+        }
+
+        // y_j = x_2j + i x_2j+1, whose transform mixes the transforms E and O of the even and odd values
+        let half = self.size / 2;
+        let mut packed = vec![FftComplex::new(0.0, 0.0); half];
+        for (y, pair) in packed.iter_mut().zip(signal.chunks(2)) {
+            *y = FftComplex::new(pair[0], pair.get(1).copied().unwrap_or(0.0));
+        }
+        self.inner.forward(&mut packed);
+
+        // E_k = (Y_k + conj(Y_(half-k))) / 2, O_k = (Y_k - conj(Y_(half-k))) / 2i, and
+        // X_k = E_k + w^k O_k, X_(k+half) = E_k - w^k O_k
+        let mut spectrum = vec![FftComplex::new(0.0, 0.0); self.size];
+        for k in 0..half {
+            let y = packed[k];
+            let mirror = packed[(half - k) % half].conj();
+            let even = FftComplex::new(
+                (y.real() + mirror.real()) / 2.0,
+                (y.imag() + mirror.imag()) / 2.0,
+            );
+            let difference = y - mirror;
+            let odd = FftComplex::new(difference.imag() / 2.0, -difference.real() / 2.0);
+            let rotated = self.twiddles[k] * odd;
+            spectrum[k] = even + rotated;
+            spectrum[k + half] = even - rotated;
+        }
+        spectrum
+    }
+
+    /// Inverse transform of a spectrum with the Hermitian symmetry, including the division by the size. Its result
+    /// is real: the imaginary parts which would come from a spectrum without this symmetry are dropped.
+    ///
+    /// # Panics
+    /// Panics if the spectrum doesn't have the size of the plan.
+    pub fn inverse(&self, spectrum: &[FftComplex]) -> Vec<f64> {
+        if spectrum.len() != self.size {
+            panic!(
+                "FFT: real plan of size {} used on data of size {}",
+                self.size,
+                spectrum.len()
+            );
+        }
+        if self.twiddles.is_empty() {
+            let mut data = spectrum.to_vec();
+            self.inner.inverse(&mut data);
+            return data.iter().map(|x| x.real()).collect();
+        }
+
+        // The transforms of the even and odd values are E_k = (X_k + X_(k+half)) / 2 and
+        // O_k = (X_k - X_(k+half)) / (2 w^k), and the inverse of E + iO packs the even and odd values
+        let half = self.size / 2;
+        let mut packed = Vec::with_capacity(half);
+        for k in 0..half {
+            let (low, high) = (spectrum[k], spectrum[k + half]);
+            let even = FftComplex::new(
+                (low.real() + high.real()) / 2.0,
+                (low.imag() + high.imag()) / 2.0,
+            );
+            let difference = (low - high) * self.twiddles[k].conj();
+            let odd = FftComplex::new(difference.real() / 2.0, difference.imag() / 2.0);
+            packed.push(FftComplex::new(
+                even.real() - odd.imag(),
+                even.imag() + odd.real(),
+            ));
+        }
+        self.inner.inverse(&mut packed);
+        packed.iter().flat_map(|y| [y.real(), y.imag()]).collect()
+    }
+}
+
+/// Thread-safe cache of fft plans, keyed by size
+#[derive(Default)]
+pub struct FftPlanner {
+    plans: RwLock<HashMap<usize, Arc<FftPlan>>>,
+    real_plans: RwLock<HashMap<usize, Arc<RealFftPlan>>>,
+}
+
+impl FftPlanner {
+    /// Creates an empty planner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Planner shared by the whole process, used by the fft-based convolutions
+    pub fn global() -> &'static FftPlanner {
+        static PLANNER: OnceLock<FftPlanner> = OnceLock::new();
+        PLANNER.get_or_init(FftPlanner::new)
+    }
+
+    /// Returns the plan of the given size, creating it on first use
+    ///
+    /// # Panics
+    /// Panics if the size is zero.
+    pub fn plan(&self, size: usize) -> Arc<FftPlan> {
+        if let Some(plan) = self.plans.read().unwrap().get(&size) {
+            return plan.clone();
+        }
+        // The plan is built before locking, so that an invalid size can't poison the lock
+        let plan = Arc::new(FftPlan::new(size));
+        self.plans
+            .write()
+            .unwrap()
+            .entry(size)
+            .or_insert(plan)
+            .clone()
+    }
+
+    /// Returns the real plan of the given size, creating it on first use. Its inner complex plan comes from the
+    /// planner as well.
+    ///
+    /// # Panics
+    /// Panics if the size is zero.
+    pub fn real_plan(&self, size: usize) -> Arc<RealFftPlan> {
+        if let Some(plan) = self.real_plans.read().unwrap().get(&size) {
+            return plan.clone();
+        }
+        if size == 0 {
+            panic!("FFT: size must be non-zero");
+        }
+        let inner_size = if size.is_multiple_of(2) {
+            size / 2
+        } else {
+            size
+        };
+        let plan = Arc::new(RealFftPlan::with_inner(size, self.plan(inner_size)));
+        self.real_plans
+            .write()
+            .unwrap()
+            .entry(size)
+            .or_insert(plan)
+            .clone()
+    }
+}
+
+/// Discrete Fourier transform of any size: X_k = sum x_n e^(-2i pi nk / size).
+///
+/// It uses the plans of the global planner, so that repeated transforms of the same size share their precomputations.
+pub fn dft(input: &[FftComplex]) -> Vec<FftComplex> {
+    let mut output = input.to_vec();
+    if !output.is_empty() {
+        FftPlanner::global().plan(output.len()).forward(&mut output);
+    }
+    output
+}
+
+/// Inverse discrete Fourier transform of any size, including the division by the size, so that
+/// inverse_dft(dft(x)) = x.
+pub fn inverse_dft(input: &[FftComplex]) -> Vec<FftComplex> {
+    let mut output = input.to_vec();
+    if !output.is_empty() {
+        FftPlanner::global().plan(output.len()).inverse(&mut output);
+    }
+    output
+}
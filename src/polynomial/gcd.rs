@@ -0,0 +1,157 @@
+//! This module implements the euclidean algorithm on polynomials: greatest common divisor,
+//! Bézout coefficients, and inverses in the modular arithmetic.
+//!
+//! Coefficients are compared to zero with a tolerance, so that float rounding errors don't prevent
+//! the algorithm from ending (see Magnitude). A tolerance of 0.0 gives the exact algorithm, as used for prime fields.
+
+use crate::algebra::Field;
+use crate::complex::Complex;
+use crate::polynomial::convolution::FastConvolution;
+use crate::polynomial::{
+    ModularArithmeticError, ModularArithmeticPolynomial, ModularArithmeticResult, Polynomial,
+    RingKind,
+};
+use crate::prime_field::Zp;
+
+/// Coefficients which the euclidean algorithm can compare to zero with a tolerance
+pub trait Magnitude {
+    /// Absolute value for floats and complex numbers. Prime field elements are exact: their magnitude is 0 for zero
+    /// and 1 otherwise, so that any tolerance below 1 gives the exact algorithm.
+    fn magnitude(self) -> f64;
+}
+
+impl Magnitude for f32 {
+    fn magnitude(self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+impl Magnitude for f64 {
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Magnitude for Complex<f32> {
+    fn magnitude(self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+impl Magnitude for Complex<f64> {
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+impl<const P: u64> Magnitude for Zp<P> {
+    fn magnitude(self) -> f64 {
+        if self.value() == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: FastConvolution + Field + Magnitude,
+{
+    /// Removes coefficients of higher degree that are not above the tolerance
+    fn trim_with_tolerance(&mut self, tolerance: f64) {
+        let len = self
+            .coefs
+            .iter()
+            .rposition(|c| c.magnitude() > tolerance)
+            .map_or(0, |deg| deg + 1);
+        self.coefs.truncate(len);
+    }
+
+    /// Divides all coefficients by the leading one
+    fn make_monic(&mut self, lead: T) {
+        for coef in self.coefs.iter_mut() {
+            *coef = *coef / lead;
+        }
+    }
+
+    /// Greatest common divisor of two polynomials, normalized to be monic.
+    ///
+    /// Remainders whose coefficients all have a modulus below the tolerance are considered zero.
+    /// The gcd of two zero polynomials is the zero polynomial.
+    pub fn gcd(&self, other: &Polynomial<T>, tolerance: f64) -> Polynomial<T> {
+        self.extended_gcd(other, tolerance).0
+    }
+
+    /// Extended euclidean algorithm: returns (g, s, t) such that s * self + t * other = g,
+    /// where g is the monic greatest common divisor of self and other.
+    ///
+    /// Remainders whose coefficients all have a modulus below the tolerance are considered zero.
+    pub fn extended_gcd(
+        &self,
+        other: &Polynomial<T>,
+        tolerance: f64,
+    ) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+        let mut r_0 = self.clone();
+        let mut r_1 = other.clone();
+        r_0.trim_with_tolerance(tolerance);
+        r_1.trim_with_tolerance(tolerance);
+
+        let mut s_0 = Polynomial::new(&[T::one()]);
+        let mut s_1 = Polynomial::new(&[]);
+        let mut t_0 = Polynomial::new(&[]);
+        let mut t_1 = Polynomial::new(&[T::one()]);
+
+        while !r_1.coefs.is_empty() {
+            let (q, mut r) = r_0
+                .div_rem(&r_1)
+                .expect("Extended gcd: divisor was checked to be non-zero");
+            r.trim_with_tolerance(tolerance);
+
+            let s = &s_0 - &(&q * &s_1);
+            let t = &t_0 - &(&q * &t_1);
+            (r_0, r_1) = (r_1, r);
+            (s_0, s_1) = (s_1, s);
+            (t_0, t_1) = (t_1, t);
+        }
+
+        if let Some(&lead) = r_0.coefs.last() {
+            r_0.make_monic(lead);
+            s_0.make_monic(lead);
+            t_0.make_monic(lead);
+        }
+        (r_0, s_0, t_0)
+    }
+}
+
+impl<T> ModularArithmeticPolynomial<T>
+where
+    T: FastConvolution + Field + Magnitude,
+{
+    /// Multiplicative inverse in the modular arithmetic, computed via the extended euclidean algorithm
+    /// with the ring modulus x^modulus - 1 (or x^modulus + 1 in a negacyclic ring).
+    ///
+    /// The polynomial is invertible if and only if it is coprime with the ring modulus. Remainders whose
+    /// coefficients all have a modulus below the tolerance are considered zero.
+    pub fn inverse(&self, tolerance: f64) -> ModularArithmeticResult<T> {
+        let mut ring_modulus = Polynomial::new_monomial(T::one(), self.modulus());
+        ring_modulus.coefs[0] += match self.kind {
+            RingKind::Cyclic => -T::one(),
+            RingKind::Negacyclic => T::one(),
+        };
+
+        let (g, s, _) = self.polynomial.extended_gcd(&ring_modulus, tolerance);
+        if g.coefs.len() != 1 {
+            return Err(ModularArithmeticError::NotInvertible(format!(
+                "Polynomial shares a factor of degree {} with the ring modulus",
+                g.coefs.len().saturating_sub(1)
+            )));
+        }
+
+        Ok(ModularArithmeticPolynomial::new_with_kind(
+            &s,
+            self.modulus(),
+            self.kind,
+        ))
+    }
+}
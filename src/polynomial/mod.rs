@@ -7,32 +7,61 @@ mod test;
 
 pub mod convolution;
 // use convolution::convolution_for_polynomial_mult_in_modular_arithmetic as convolution;
-use convolution::convolution;
-
-use crate::complex;
-use crate::complex::Number;
+use convolution::{convolution, linear_convolution, FastConvolution};
+
+mod algebraic;
+mod division;
+mod evaluation;
+pub mod fft;
+mod gcd;
+mod multipoint;
+pub mod ntt;
+mod quotient;
+mod roots;
+mod series;
+pub mod tuning;
+pub use algebraic::{AlgebraicCoefficient, AlgebraicFormat};
+pub use evaluation::{EvaluationDomain, EvaluationForm};
+pub use gcd::Magnitude;
+pub use multipoint::SubproductTree;
+pub use quotient::QuotientRingPolynomial;
+pub use roots::{Root, RootFindingOptions};
+pub use series::{PowerSeries, PowerSeriesError};
+
+use crate::algebra::CommutativeRing;
 
 use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
+/// Polynomial error types
+#[derive(Debug)]
+pub enum PolynomialError {
+    DivisionByZero(String),
+    ZeroPolynomial(String),
+    NotConverged(String),
+    WrongInputSize(String),
+    DuplicatedPoints(String),
+    ParseError(String),
+}
+type PolynomialResult<T> = Result<Polynomial<T>, PolynomialError>;
+
 /// Type defining a general polynomial:
 /// We store all coefficients in a Vec, its index in the Vec representing its degree.
 /// This sadly means it leaves on the heap, and cannot have the Copy trait.
 /// TODO: If the maximum degree can be known at compile time, this could be on the stack,
 /// and be made much more efficiently.
 #[derive(Clone, Default)]
-pub struct Polynomial<T: Number> {
+pub struct Polynomial<T: CommutativeRing> {
     coefs: Vec<T>,
 }
 
 /// Implement the Display trait
-impl<T: Number> std::fmt::Display for Polynomial<T> {
+impl<T: CommutativeRing> std::fmt::Display for Polynomial<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut ret = "[".to_string();
-        for i in 0..self.coefs.len() - 1 {
-            ret.push_str(&self.coefs[i].to_string());
-            ret.push_str(", ");
-        }
-        if let Some(c) = self.coefs.last() {
+        for (i, c) in self.coefs.iter().enumerate() {
+            if i > 0 {
+                ret.push_str(", ");
+            }
             ret.push_str(&c.to_string());
         }
         ret.push(']');
@@ -40,7 +69,7 @@ impl<T: Number> std::fmt::Display for Polynomial<T> {
     }
 }
 
-impl<T: Number> Polynomial<T> {
+impl<T: CommutativeRing> Polynomial<T> {
     /// Creates a polynomial: This array must contains coefficients stored in the order of
     /// their degree.
     pub fn new(arr: &[T]) -> Self {
@@ -51,15 +80,15 @@ impl<T: Number> Polynomial<T> {
 
     /// Create a new monomial from one coefficient and its degree
     pub fn new_monomial(coef: T, deg: usize) -> Self {
-        let mut ret = Polynomial::new(&vec![T::from(0.0); deg]);
+        let mut ret = Polynomial::new(&vec![T::zero(); deg]);
         ret.coefs.push(coef);
         ret
     }
 
     /// Applies the polynomial, as a function, on an input
     pub fn apply(&self, x: T) -> T {
-        let mut ret = T::from(0.0);
-        let mut x_powers = T::from(1.0);
+        let mut ret = T::zero();
+        let mut x_powers = T::one();
         for deg in 0..self.coefs.len() {
             ret += self.coefs[deg] * x_powers;
             x_powers *= x;
@@ -67,6 +96,31 @@ impl<T: Number> Polynomial<T> {
         ret
     }
 
+    /// Degree of the polynomial, ignoring zero coefficients of higher degree.
+    /// The zero polynomial has no degree.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefs.iter().rposition(|c| *c != T::zero())
+    }
+
+    /// Removes zero coefficients of higher degree than the polynomial degree
+    fn trim(&mut self) {
+        let len = self.degree().map_or(0, |deg| deg + 1);
+        self.coefs.truncate(len);
+    }
+
+    /// Computes the derivative of the polynomial
+    pub fn derivative(&self) -> Polynomial<T> {
+        Polynomial {
+            coefs: self
+                .coefs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(deg, &c)| T::from_integer(deg as i64) * c)
+                .collect(),
+        }
+    }
+
     /// Public getter for a coef
     pub fn coef(&self, n: usize) -> T {
         self.coefs[n]
@@ -99,17 +153,17 @@ impl<T: Number> Polynomial<T> {
 }
 
 /// The AddAssign operation for polynomials references
-impl<'a, T: Number> AddAssign<&'a Polynomial<T>> for Polynomial<T> {
+impl<'a, T: CommutativeRing> AddAssign<&'a Polynomial<T>> for Polynomial<T> {
     fn add_assign(&mut self, other: &'a Polynomial<T>) {
         if other.coefs.len() > self.coefs.len() {
-            self.coefs.resize(other.coefs.len(), T::from(0.0));
+            self.coefs.resize(other.coefs.len(), T::zero());
         }
         self.add_to_self(&other);
     }
 }
 
 /// The Add operation for polynomials references.
-impl<'a, T: Number> Add for &'a Polynomial<T> {
+impl<'a, T: CommutativeRing> Add for &'a Polynomial<T> {
     type Output = Polynomial<T>;
 
     fn add(self, other: &'a Polynomial<T>) -> Polynomial<T> {
@@ -122,29 +176,28 @@ impl<'a, T: Number> Add for &'a Polynomial<T> {
 }
 
 /// The SubAssign operation for polynomials references
-impl<'a, T: Number> SubAssign<&'a Polynomial<T>> for Polynomial<T> {
+impl<'a, T: CommutativeRing> SubAssign<&'a Polynomial<T>> for Polynomial<T> {
     fn sub_assign(&mut self, other: &'a Polynomial<T>) {
         if other.coefs.len() > self.coefs.len() {
-            self.coefs.resize(other.coefs.len(), T::from(0.0));
+            self.coefs.resize(other.coefs.len(), T::zero());
         }
         self.sub_to_self(&other);
     }
 }
 
 /// The Sub operation for polynomials references.
-impl<'a, T: Number> Sub for &'a Polynomial<T> {
+impl<'a, T: CommutativeRing> Sub for &'a Polynomial<T> {
     type Output = Polynomial<T>;
 
     fn sub(self, other: &'a Polynomial<T>) -> Polynomial<T> {
         let mut ret = self.clone();
-        ret.coefs.resize(other.coefs.len(), T::from(0.0));
         ret -= other;
         ret
     }
 }
 
 /// The Neg operattion for polynomials references
-impl<'a, T: Number> Neg for &'a Polynomial<T> {
+impl<'a, T: CommutativeRing> Neg for &'a Polynomial<T> {
     type Output = Polynomial<T>;
 
     fn neg(self) -> Polynomial<T> {
@@ -156,41 +209,89 @@ impl<'a, T: Number> Neg for &'a Polynomial<T> {
     }
 }
 
+/// The Mul operation for polynomials references.
+///
+/// This returns the full product, of degree deg(self) + deg(other), and the polynomials can have different sizes.
+/// The convolution of the coefficients is naive for low degrees, uses Karatsuba algorithm for medium degrees,
+/// and is fft-based (or ntt-based for prime fields) for higher degrees.
+impl<'a, T> Mul for &'a Polynomial<T>
+where
+    T: FastConvolution,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: &'a Polynomial<T>) -> Polynomial<T> {
+        Polynomial {
+            coefs: linear_convolution(&self.coefs, &other.coefs),
+        }
+    }
+}
+
 /// Modular arithmetic error types
 #[derive(Debug)]
 pub enum ModularArithmeticError {
     ModulusMismatched(String),
     DegreeAboveModulus(String),
+    RingKindMismatched(String),
+    ModulusPolynomialMismatched(String),
+    ModulusNotMonic(String),
+    NotInvertible(String),
+    NoEvaluationDomain(String),
 }
 type ModularArithmeticResult<T> = Result<ModularArithmeticPolynomial<T>, ModularArithmeticError>;
 
-/// Type representing a polynomial mod(x^modulus - 1).
+/// The quotient ring a modular polynomial lives in
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum RingKind {
+    /// Polynomials mod(x^modulus - 1): terms of higher degree spill over with the same sign
+    #[default]
+    Cyclic,
+    /// Polynomials mod(x^modulus + 1): terms of higher degree spill over with a flipped sign
+    Negacyclic,
+}
+
+/// Type representing a polynomial mod(x^modulus - 1), or mod(x^modulus + 1) for a negacyclic ring.
 /// The coefs Vec inside polynomial must have length modulus.
 #[derive(Clone, Default)]
-pub struct ModularArithmeticPolynomial<T: Number> {
+pub struct ModularArithmeticPolynomial<T: CommutativeRing> {
     polynomial: Polynomial<T>,
+    kind: RingKind,
 }
 
 /// Implement the Display trait
-impl<T: Number> std::fmt::Display for ModularArithmeticPolynomial<T> {
+impl<T: CommutativeRing> std::fmt::Display for ModularArithmeticPolynomial<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.polynomial.to_string())
     }
 }
 
-impl<T: Number> ModularArithmeticPolynomial<T> {
+impl<T: CommutativeRing> ModularArithmeticPolynomial<T> {
     /// Polynomial doesn't need to already respect the modular arithmetic
     pub fn new(poly: &Polynomial<T>, modulus: usize) -> Self {
+        Self::new_with_kind(poly, modulus, RingKind::Cyclic)
+    }
+
+    /// Constructor for a polynomial mod(x^modulus + 1)
+    pub fn new_negacyclic(poly: &Polynomial<T>, modulus: usize) -> Self {
+        Self::new_with_kind(poly, modulus, RingKind::Negacyclic)
+    }
+
+    /// Constructor for any kind of ring
+    pub fn new_with_kind(poly: &Polynomial<T>, modulus: usize, kind: RingKind) -> Self {
         Self {
-            polynomial: Self::sanitize(poly, modulus),
+            polynomial: Self::sanitize(poly, modulus, kind),
+            kind,
         }
     }
 
     /// Constructor for a zero polynomial
     pub fn new_zero(modulus: usize) -> Self {
-        Self {
-            polynomial: Self::sanitize(&Polynomial::<T>::new(&[]), modulus),
-        }
+        Self::new_zero_with_kind(modulus, RingKind::Cyclic)
+    }
+
+    /// Constructor for a zero polynomial in any kind of ring
+    pub fn new_zero_with_kind(modulus: usize, kind: RingKind) -> Self {
+        Self::new_with_kind(&Polynomial::<T>::new(&[]), modulus, kind)
     }
 
     /// Calls the underlying polynomial call function
@@ -224,36 +325,90 @@ impl<T: Number> ModularArithmeticPolynomial<T> {
         self.polynomial.coefs.len()
     }
 
+    /// All the coefficients, of which there are modulus
+    pub(crate) fn coefs(&self) -> &[T] {
+        &self.polynomial.coefs
+    }
+
+    /// Public getter for the kind of ring
+    pub fn kind(&self) -> RingKind {
+        self.kind
+    }
+
     /// Computes a lowest degree polynomial congruent to the input one in the modular arithmetic
-    fn sanitize(poly: &Polynomial<T>, modulus: usize) -> Polynomial<T> {
+    ///
+    /// In a negacyclic ring, x^modulus = -1, so the sign of spilled over terms flips every time
+    /// they wrap around.
+    fn sanitize(poly: &Polynomial<T>, modulus: usize, kind: RingKind) -> Polynomial<T> {
         let mut ret = poly.clone();
-        ret.coefs.resize(modulus, T::from(0.0));
+        ret.coefs.resize(modulus, T::zero());
 
         let size = poly.coefs.len();
         let mut reduced_i = 0;
+        let mut negate = kind == RingKind::Negacyclic;
         for i in modulus..size {
-            ret.coefs[reduced_i] += poly.coefs[i];
+            if negate {
+                ret.coefs[reduced_i] -= poly.coefs[i];
+            } else {
+                ret.coefs[reduced_i] += poly.coefs[i];
+            }
             reduced_i += 1;
-            reduced_i -= ((reduced_i == modulus) as usize) * modulus;
+            if reduced_i == modulus {
+                reduced_i = 0;
+                negate ^= kind == RingKind::Negacyclic;
+            }
         }
 
         ret
     }
 
+    /// In place addition, which returns an error instead of panicking on a mismatch of moduli
+    pub fn checked_add_assign(
+        &mut self,
+        other: &ModularArithmeticPolynomial<T>,
+    ) -> Result<(), ModularArithmeticError> {
+        self.check_modulus(other)?;
+        self.polynomial.add_to_self(&other.polynomial);
+        Ok(())
+    }
+
+    /// In place subtraction, which returns an error instead of panicking on a mismatch of moduli
+    pub fn checked_sub_assign(
+        &mut self,
+        other: &ModularArithmeticPolynomial<T>,
+    ) -> Result<(), ModularArithmeticError> {
+        self.check_modulus(other)?;
+        self.polynomial.sub_to_self(&other.polynomial);
+        Ok(())
+    }
+
     /// Check the modulus of another polynomial against this one
-    fn check_modulus(
+    pub(crate) fn check_modulus(
         &self,
         other: &ModularArithmeticPolynomial<T>,
     ) -> Result<(), ModularArithmeticError> {
-        if self.modulus() != other.modulus() {
-            return Err(ModularArithmeticError::ModulusMismatched(format!(
-                "Modulus mismatch: {}, {}",
-                self.modulus(),
-                other.modulus()
-            )));
-        }
-        Ok(())
+        check_ring((self.modulus(), self.kind), (other.modulus(), other.kind))
+    }
+}
+
+/// Check that two (modulus, kind) pairs describe the same ring
+pub(crate) fn check_ring(
+    ring: (usize, RingKind),
+    other: (usize, RingKind),
+) -> Result<(), ModularArithmeticError> {
+    if ring.0 != other.0 {
+        return Err(ModularArithmeticError::ModulusMismatched(format!(
+            "Modulus mismatch: {}, {}",
+            ring.0, other.0
+        )));
+    }
+    if ring.1 != other.1 {
+        return Err(ModularArithmeticError::RingKindMismatched(format!(
+            "Ring kind mismatch: {:?}, {:?}",
+            ring.1, other.1
+        )));
     }
+    Ok(())
 }
 
 /// The Add operation for polynomials references in a modular arithmetic.
@@ -261,13 +416,14 @@ impl<T: Number> ModularArithmeticPolynomial<T> {
 /// This operation runs on references to avoid borrowing values (since Polynomial
 /// doesn't implement the Copy trait). This returns a Result because there potentially
 /// could be a mismatch of moduli between the two polynomials.
-impl<'a, T: Number> Add for &'a ModularArithmeticPolynomial<T> {
+impl<'a, T: CommutativeRing> Add for &'a ModularArithmeticPolynomial<T> {
     type Output = ModularArithmeticResult<T>;
 
     fn add(self, other: &'a ModularArithmeticPolynomial<T>) -> ModularArithmeticResult<T> {
         self.check_modulus(&other)?;
         Ok(ModularArithmeticPolynomial::<T> {
             polynomial: Polynomial::<T>::add_internal(&self.polynomial, &other.polynomial),
+            kind: self.kind,
         })
     }
 }
@@ -276,13 +432,12 @@ impl<'a, T: Number> Add for &'a ModularArithmeticPolynomial<T> {
 ///
 /// This operation can potentially panic, if the two polynomials don't have
 /// the same modulus
-impl<'a, T: Number> AddAssign<&'a ModularArithmeticPolynomial<T>>
+impl<'a, T: CommutativeRing> AddAssign<&'a ModularArithmeticPolynomial<T>>
     for ModularArithmeticPolynomial<T>
 {
     fn add_assign(&mut self, other: &'a ModularArithmeticPolynomial<T>) {
-        self.check_modulus(&other)
+        self.checked_add_assign(other)
             .expect("AddAssign in modular arithmetic: modulus mismatched");
-        self.polynomial.add_to_self(&other.polynomial);
     }
 }
 
@@ -291,14 +446,17 @@ impl<'a, T: Number> AddAssign<&'a ModularArithmeticPolynomial<T>>
 /// This operation runs on references to avoid borrowing values (since Polynomial
 /// doesn't implement the Copy trait). This returns a Result because there potentially
 /// could be a mismatch of moduli between the two polynomials.
-impl<'a, T: Number> Sub for &'a ModularArithmeticPolynomial<T> {
+impl<'a, T: CommutativeRing> Sub for &'a ModularArithmeticPolynomial<T> {
     type Output = ModularArithmeticResult<T>;
 
     fn sub(self, other: &'a ModularArithmeticPolynomial<T>) -> ModularArithmeticResult<T> {
         self.check_modulus(&other)?;
         let mut ret = self.polynomial.clone();
         ret -= &other.polynomial;
-        Ok(ModularArithmeticPolynomial::<T> { polynomial: ret })
+        Ok(ModularArithmeticPolynomial::<T> {
+            polynomial: ret,
+            kind: self.kind,
+        })
     }
 }
 
@@ -306,13 +464,12 @@ impl<'a, T: Number> Sub for &'a ModularArithmeticPolynomial<T> {
 ///
 /// This operation can potentially panic, if the two polynomials don't have
 /// the same modulus
-impl<'a, T: Number> SubAssign<&'a ModularArithmeticPolynomial<T>>
+impl<'a, T: CommutativeRing> SubAssign<&'a ModularArithmeticPolynomial<T>>
     for ModularArithmeticPolynomial<T>
 {
     fn sub_assign(&mut self, other: &'a ModularArithmeticPolynomial<T>) {
-        self.check_modulus(&other)
+        self.checked_sub_assign(other)
             .expect("SubAssign in modular arithmetic: modulus mismatched");
-        self.polynomial.sub_to_self(&other.polynomial);
     }
 }
 
@@ -320,12 +477,13 @@ impl<'a, T: Number> SubAssign<&'a ModularArithmeticPolynomial<T>>
 ///
 /// This operation runs on references to avoid borrowing values (since Polynomial
 /// doesn't implement the Copy trait).
-impl<'a, T: Number> Neg for &'a ModularArithmeticPolynomial<T> {
+impl<'a, T: CommutativeRing> Neg for &'a ModularArithmeticPolynomial<T> {
     type Output = ModularArithmeticPolynomial<T>;
 
     fn neg(self) -> ModularArithmeticPolynomial<T> {
         ModularArithmeticPolynomial {
             polynomial: -&self.polynomial,
+            kind: self.kind,
         }
     }
 }
@@ -337,26 +495,38 @@ impl<'a, T: Number> Neg for &'a ModularArithmeticPolynomial<T> {
 /// coefficient representation of the polynoms to turn the convolution into a dot product, and the complexity
 /// is then O(nlog(n))
 ///
+/// In a negacyclic ring the full product is computed, and higher degree terms are folded back with a flipped sign.
+///
 /// This operation runs on references to avoid borrowing values (since Polynomial
 /// doesn't implement the Copy trait). This returns a Result because there potentially
 /// could be a mismatch of moduli between the two polynomials.
 impl<'a, T> Mul for &'a ModularArithmeticPolynomial<T>
 where
-    T: Number + From<complex::Complex<f64>>,
-    complex::Complex<f64>: From<T>,
+    T: FastConvolution,
 {
     type Output = ModularArithmeticResult<T>;
 
     fn mul(self, other: &'a ModularArithmeticPolynomial<T>) -> ModularArithmeticResult<T> {
         self.check_modulus(&other)?;
 
-        let convolution = convolution(&self.polynomial.coefs, &other.polynomial.coefs);
+        let convolution = match self.kind {
+            RingKind::Cyclic => convolution(&self.polynomial.coefs, &other.polynomial.coefs),
+            RingKind::Negacyclic => {
+                linear_convolution(&self.polynomial.coefs, &other.polynomial.coefs)
+            }
+        };
 
-        Ok(ModularArithmeticPolynomial::<T>::new(
+        Ok(ModularArithmeticPolynomial::<T>::new_with_kind(
             &Polynomial::<T>::new(&convolution),
             self.modulus(),
+            self.kind,
         ))
     }
 }
 
-impl<T: Number> crate::matrix::MatrixInput for ModularArithmeticPolynomial<T> {}
+/// Polynomials of a matrix must all live in the same ring
+impl<T: CommutativeRing> crate::matrix::MatrixInput for ModularArithmeticPolynomial<T> {
+    fn check_compatible(&self, other: &Self) -> Result<(), crate::matrix::MatrixError> {
+        Ok(self.check_modulus(other)?)
+    }
+}
@@ -0,0 +1,169 @@
+//! This module implements fast multipoint evaluation and interpolation of polynomials, using subproduct trees.
+//!
+//! The subproduct tree of points x_0, ..., x_(n-1) has the linear polynomials (x - x_i) as leaves, and each node
+//! is the product of its children, the root being the product of all (x - x_i). Evaluating a polynomial at all
+//! points amounts to taking remainders down the tree, and interpolating amounts to recombining weighted values up the
+//! tree. Since products are fft-based and remainders use Newton iteration, both operations cost O(nlog²(n)).
+//!
+//! With float coefficients, the order of the points matters: the tree is well conditioned when the points of each
+//! subtree are spread out, for example roots of unity in bit reversed order. Neighbouring points grouped in the same
+//! subtree lead to nodes with huge coefficients, and to a loss of precision.
+
+use crate::algebra::{CommutativeRing, Field};
+use crate::polynomial::convolution::FastConvolution;
+use crate::polynomial::{Polynomial, PolynomialError, PolynomialResult};
+
+/// Below this number of points, evaluating the polynomial at each point is faster than going through the tree
+const MULTIPOINT_THRESHOLD: usize = 32;
+
+/// Subproduct tree built on a set of points. It can be reused to evaluate or interpolate many polynomials
+/// on the same points.
+pub struct SubproductTree<T: CommutativeRing> {
+    points: Vec<T>,
+    /// levels[0] contains the leaves, and the last level contains only the root
+    levels: Vec<Vec<Polynomial<T>>>,
+}
+
+impl<T> SubproductTree<T>
+where
+    T: FastConvolution + Field,
+{
+    /// Builds the subproduct tree: when a level has an odd number of nodes, the last one is carried
+    /// over to the next level
+    pub fn new(points: &[T]) -> Self {
+        let leaves: Vec<Polynomial<T>> = points
+            .iter()
+            .map(|&x| Polynomial::new(&[-x, T::one()]))
+            .collect();
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next_level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        Self {
+            points: points.to_vec(),
+            levels,
+        }
+    }
+
+    /// Public getter for the points
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// The product of all (x - x_i)
+    pub fn root(&self) -> Polynomial<T> {
+        match self.levels.last().and_then(|level| level.first()) {
+            Some(root) => root.clone(),
+            None => Polynomial::new(&[T::one()]),
+        }
+    }
+
+    /// Evaluates the polynomial at every point of the tree.
+    ///
+    /// Going down the tree, a polynomial is replaced by its remainder modulo each child: the remainder modulo
+    /// (x - x_i) is the value at x_i.
+    pub fn evaluate(&self, poly: &Polynomial<T>) -> Vec<T> {
+        if self.points.len() <= MULTIPOINT_THRESHOLD {
+            return self.points.iter().map(|&x| poly.apply(x)).collect();
+        }
+
+        let mut remainders = vec![self.remainder(poly, &self.root())];
+        for level in self.levels.iter().skip(1).rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, node)| self.remainder(&remainders[i / 2], node))
+                .collect();
+        }
+
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| remainders[i / 2].apply(x))
+            .collect()
+    }
+
+    /// Computes the unique polynomial of degree lower than the number of points, which takes the given values
+    /// at the points of the tree.
+    ///
+    /// The Lagrange interpolation formula is written as sum of c_i * M(x) / (x - x_i), where M is the root of the tree
+    /// and c_i = y_i / M'(x_i). These terms are recombined going up the tree.
+    ///
+    /// Duplicated points are reported when M'(x_i) is exactly zero, which is always the case in exact fields. With
+    /// floating point values, only exact duplicates are detected: points which are merely very close give huge
+    /// coefficients c_i, and an ill-conditioned result.
+    pub fn interpolate(&self, values: &[T]) -> PolynomialResult<T> {
+        if values.len() != self.points.len() {
+            return Err(PolynomialError::WrongInputSize(format!(
+                "Interpolation needs as many values as points: {} instead of {}",
+                values.len(),
+                self.points.len()
+            )));
+        }
+
+        let weights = self.evaluate(&self.root().derivative());
+        let mut combinations = Vec::<Polynomial<T>>::with_capacity(values.len());
+        for i in 0..values.len() {
+            if weights[i] == T::zero() {
+                return Err(PolynomialError::DuplicatedPoints(format!(
+                    "Interpolation point {} appears more than once",
+                    self.points[i]
+                )));
+            }
+            combinations.push(Polynomial::new(&[values[i] / weights[i]]));
+        }
+
+        for level in self.levels.iter().take(self.levels.len().saturating_sub(1)) {
+            combinations = combinations
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(comb, nodes)| match (comb, nodes) {
+                    ([comb_left, comb_right], [left, right]) => {
+                        &(comb_left * right) + &(comb_right * left)
+                    }
+                    _ => comb[0].clone(),
+                })
+                .collect();
+        }
+
+        let mut ret = combinations.pop().unwrap_or_else(|| Polynomial::new(&[]));
+        ret.coefs.resize(self.points.len(), T::zero());
+        Ok(ret)
+    }
+
+    /// Remainder of the euclidean division, skipping the division when the degree is already low enough
+    fn remainder(&self, poly: &Polynomial<T>, modulus: &Polynomial<T>) -> Polynomial<T> {
+        if poly.coefs.len() < modulus.coefs.len() {
+            return poly.clone();
+        }
+        poly.div_rem(modulus)
+            .expect("Subproduct tree: nodes are monic, hence non-zero")
+            .1
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: FastConvolution + Field,
+{
+    /// Applies the polynomial on many inputs at once, via a subproduct tree
+    pub fn apply_many(&self, points: &[T]) -> Vec<T> {
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Creates the polynomial of lowest degree going through all the (points[i], values[i]) pairs
+    pub fn interpolate(points: &[T], values: &[T]) -> PolynomialResult<T> {
+        SubproductTree::new(points).interpolate(values)
+    }
+}
@@ -0,0 +1,119 @@
+//! This module implements the number theoretic transform (ntt), which is the fft over a prime field Z/pZ.
+//!
+//! Complex roots of unity are replaced by roots of unity of the field, so that the convolution theorem holds
+//! with exact arithmetic: there is no rounding error, whatever the size or the values of the coefficients.
+//! A transform of size 2^k needs 2^k to divide p - 1, which is the case up to 2^23 for 998244353, and up to 2^32
+//! for 2^64 - 2^32 + 1.
+
+use crate::polynomial::convolution::{fold_cyclic, toom3_linear_convolution};
+use crate::prime_field::Zp;
+
+/// The ntt-based convolution, accepting inputs of different sizes.
+///
+/// Both inputs are padded with zeros up to the first power of 2 above the output size. If the field has no root of
+/// unity of that order, this falls back to the Toom-3 convolution.
+pub fn linear_convolution_via_ntt<const P: u64>(a: &[Zp<P>], b: &[Zp<P>]) -> Vec<Zp<P>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let output_size = a.len() + b.len() - 1;
+    let target_size = output_size.next_power_of_two();
+    let root = match Zp::<P>::root_of_unity(target_size.trailing_zeros()) {
+        Some(root) => root,
+        None => return toom3_linear_convolution(a, b),
+    };
+
+    let mut a_values = a.to_vec();
+    let mut b_values = b.to_vec();
+    a_values.resize(target_size, Zp::new(0));
+    b_values.resize(target_size, Zp::new(0));
+    let mut product = cyclic_product(a_values, b_values, root);
+    product.truncate(output_size);
+    product
+}
+
+/// The ntt-based cyclic convolution, where both inputs have the same size n and the output is the product modulo
+/// x^n - 1.
+///
+/// When n is a power of 2 and the field has a root of unity of order n, the transform is done at size n directly,
+/// without padding. Otherwise the full product is computed and folded.
+pub fn cyclic_convolution_via_ntt<const P: u64>(a: &[Zp<P>], b: &[Zp<P>]) -> Vec<Zp<P>> {
+    assert!(a.len() == b.len());
+    let size = a.len();
+    let root = if size.is_power_of_two() {
+        Zp::<P>::root_of_unity(size.trailing_zeros())
+    } else {
+        None
+    };
+    match root {
+        Some(root) => cyclic_product(a.to_vec(), b.to_vec(), root),
+        None => fold_cyclic(linear_convolution_via_ntt(a, b), size),
+    }
+}
+
+/// Cyclic convolution of two inputs of the same size, with root a primitive root of unity of that order
+fn cyclic_product<const P: u64>(
+    mut a_values: Vec<Zp<P>>,
+    mut b_values: Vec<Zp<P>>,
+    root: Zp<P>,
+) -> Vec<Zp<P>> {
+    let size = a_values.len();
+    ntt(&mut a_values, root);
+    ntt(&mut b_values, root);
+
+    for (x, &y) in a_values.iter_mut().zip(b_values.iter()) {
+        *x *= y;
+    }
+
+    // The backward transform is the forward one with the inverse root, followed by a division by the size
+    let root_inverse = root.inverse().expect("Roots of unity are non-zero");
+    ntt(&mut a_values, root_inverse);
+    let size_inverse = Zp::<P>::new(size as u64)
+        .inverse()
+        .expect("Sizes of transforms are smaller than P");
+
+    for x in a_values.iter_mut() {
+        *x *= size_inverse;
+    }
+    a_values
+}
+
+/// In place iterative ntt, where root is a primitive root of unity of order values.len(), a power of 2.
+///
+/// Values are first sorted in bit reversed order, then merged with butterflies of increasing size.
+pub fn ntt<const P: u64>(values: &mut [Zp<P>], root: Zp<P>) {
+    let size = values.len();
+    if size <= 1 {
+        return;
+    }
+    let log_size = size.trailing_zeros();
+
+    for i in 0..size {
+        let j = i.reverse_bits() >> (usize::BITS - log_size);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut half = 1;
+    while half < size {
+        // Root of unity of order 2 * half
+        let step_root = root.pow((size / (2 * half)) as u64);
+        let mut twiddles = Vec::with_capacity(half);
+        let mut twiddle = Zp::<P>::new(1);
+        for _ in 0..half {
+            twiddles.push(twiddle);
+            twiddle *= step_root;
+        }
+
+        for block in values.chunks_exact_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+            for ((l, h), &w) in low.iter_mut().zip(high.iter_mut()).zip(twiddles.iter()) {
+                let product = *h * w;
+                *h = *l - product;
+                *l += product;
+            }
+        }
+        half *= 2;
+    }
+}
@@ -0,0 +1,199 @@
+//! This module implements polynomial arithmetic in a general quotient ring K[x]/(f(x)),
+//! where the modulus f is any monic polynomial.
+
+use crate::algebra::CommutativeRing;
+use crate::polynomial::convolution::{linear_convolution, FastConvolution};
+use crate::polynomial::{
+    AlgebraicCoefficient, AlgebraicFormat, ModularArithmeticError, Polynomial,
+};
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+type QuotientRingResult<T> = Result<QuotientRingPolynomial<T>, ModularArithmeticError>;
+
+/// Type representing a polynomial mod(f(x)), with f a monic polynomial.
+/// The coefs Vec inside polynomial must have length deg(f), and the modulus is stored without
+/// any zero coefficient above its degree.
+#[derive(Clone)]
+pub struct QuotientRingPolynomial<T: CommutativeRing> {
+    polynomial: Polynomial<T>,
+    modulus: Polynomial<T>,
+}
+
+/// Implement the Display trait
+impl<T: CommutativeRing> std::fmt::Display for QuotientRingPolynomial<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.polynomial.to_string())
+    }
+}
+
+impl<T: CommutativeRing> QuotientRingPolynomial<T> {
+    /// Polynomial doesn't need to already respect the modular arithmetic, but the modulus must be monic
+    pub fn new(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> QuotientRingResult<T> {
+        let mut modulus = modulus.clone();
+        modulus.trim();
+        if modulus.coefs.last() != Some(&T::one()) {
+            return Err(ModularArithmeticError::ModulusNotMonic(format!(
+                "Modulus polynomial is not monic: {}",
+                modulus
+            )));
+        }
+
+        Ok(Self {
+            polynomial: Self::reduce(poly, &modulus),
+            modulus,
+        })
+    }
+
+    /// Constructor for a zero polynomial
+    pub fn new_zero(modulus: &Polynomial<T>) -> QuotientRingResult<T> {
+        Self::new(&Polynomial::<T>::new(&[]), modulus)
+    }
+
+    /// Calls the underlying polynomial call function
+    pub fn apply(&self, x: T) -> T {
+        self.polynomial.apply(x)
+    }
+
+    /// Check coefficient access
+    fn check_coef(&self, n: usize) -> Result<(), ModularArithmeticError> {
+        if n >= self.polynomial.coefs.len() {
+            return Err(ModularArithmeticError::DegreeAboveModulus(format!(
+                "Degree {} higher than or equal to modulus degree {}",
+                n,
+                self.polynomial.coefs.len()
+            )));
+        }
+        Ok(())
+    }
+    /// Public getter for a coef
+    pub fn coef(&self, n: usize) -> Result<T, ModularArithmeticError> {
+        self.check_coef(n)?;
+        Ok(self.polynomial.coef(n))
+    }
+    /// Public setter for a coef
+    pub fn coef_mut(&mut self, n: usize) -> Result<&mut T, ModularArithmeticError> {
+        self.check_coef(n)?;
+        Ok(self.polynomial.coef_mut(n))
+    }
+
+    /// Public getter for the modulus polynomial
+    pub fn modulus(&self) -> &Polynomial<T> {
+        &self.modulus
+    }
+
+    /// Computes the remainder of the input in the division by the (monic) modulus.
+    ///
+    /// Because the modulus is monic, no coefficient division is needed: each term of degree
+    /// d >= deg(f) is removed by subtracting the right multiple of x^(d - deg(f)) * f.
+    fn reduce(poly: &Polynomial<T>, modulus: &Polynomial<T>) -> Polynomial<T> {
+        let deg = modulus.coefs.len() - 1;
+        let mut coefs = poly.coefs.clone();
+
+        for i in (deg..coefs.len()).rev() {
+            let lead = coefs[i];
+            for j in 0..deg {
+                coefs[i - deg + j] -= lead * modulus.coefs[j];
+            }
+        }
+
+        coefs.resize(deg, T::zero());
+        Polynomial { coefs }
+    }
+
+    /// Check the modulus of another polynomial against this one
+    fn check_modulus(
+        &self,
+        other: &QuotientRingPolynomial<T>,
+    ) -> Result<(), ModularArithmeticError> {
+        if self.modulus.coefs != other.modulus.coefs {
+            return Err(ModularArithmeticError::ModulusPolynomialMismatched(
+                format!(
+                    "Modulus polynomial mismatch: {}, {}",
+                    self.modulus, other.modulus
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The Add operation for polynomials references in a quotient ring.
+///
+/// This returns a Result because there potentially could be a mismatch of moduli between the two polynomials.
+impl<'a, T: CommutativeRing> Add for &'a QuotientRingPolynomial<T> {
+    type Output = QuotientRingResult<T>;
+
+    fn add(self, other: &'a QuotientRingPolynomial<T>) -> QuotientRingResult<T> {
+        self.check_modulus(other)?;
+        Ok(QuotientRingPolynomial::<T> {
+            polynomial: &self.polynomial + &other.polynomial,
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+/// The Sub operation for polynomials references in a quotient ring.
+///
+/// This returns a Result because there potentially could be a mismatch of moduli between the two polynomials.
+impl<'a, T: CommutativeRing> Sub for &'a QuotientRingPolynomial<T> {
+    type Output = QuotientRingResult<T>;
+
+    fn sub(self, other: &'a QuotientRingPolynomial<T>) -> QuotientRingResult<T> {
+        self.check_modulus(other)?;
+        Ok(QuotientRingPolynomial::<T> {
+            polynomial: &self.polynomial - &other.polynomial,
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+/// The Neg operation for polynomials references in a quotient ring.
+impl<T: CommutativeRing> Neg for &QuotientRingPolynomial<T> {
+    type Output = QuotientRingPolynomial<T>;
+
+    fn neg(self) -> QuotientRingPolynomial<T> {
+        QuotientRingPolynomial {
+            polynomial: -&self.polynomial,
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+/// The Mul operation for polynomials references in a quotient ring.
+///
+/// The full product is computed with the same naive or fft-based convolution as in the modular arithmetic,
+/// and is then reduced by the modulus polynomial.
+///
+/// This returns a Result because there potentially could be a mismatch of moduli between the two polynomials.
+impl<'a, T> Mul for &'a QuotientRingPolynomial<T>
+where
+    T: FastConvolution,
+{
+    type Output = QuotientRingResult<T>;
+
+    fn mul(self, other: &'a QuotientRingPolynomial<T>) -> QuotientRingResult<T> {
+        self.check_modulus(other)?;
+
+        let convolution = linear_convolution(&self.polynomial.coefs, &other.polynomial.coefs);
+
+        Ok(QuotientRingPolynomial::<T> {
+            polynomial: QuotientRingPolynomial::reduce(
+                &Polynomial::<T>::new(&convolution),
+                &self.modulus,
+            ),
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+impl<T: AlgebraicCoefficient> QuotientRingPolynomial<T> {
+    /// Writes the polynomial in algebraic notation, followed by the modulus polynomial, such as "x + 1 mod (x^2 + 1)"
+    pub fn to_algebraic_string(&self, format: &AlgebraicFormat) -> String {
+        format!(
+            "{} mod ({})",
+            self.polynomial.to_algebraic_string(format),
+            self.modulus.to_algebraic_string(format)
+        )
+    }
+}
@@ -0,0 +1,219 @@
+//! This module implements root finding for complex polynomials, with the Aberth-Ehrlich method.
+//!
+//! All roots are approximated simultaneously: each approximation follows a Newton step, corrected by
+//! the repulsion from all other approximations so that they don't converge to the same root.
+//! Approximations that end up in the same cluster are then merged into a multiple root, and each root
+//! is polished with a few Newton iterations.
+
+use crate::complex::Complex;
+use crate::polynomial::{Polynomial, PolynomialError};
+
+type RootComplex = Complex<f64>;
+
+/// Parameters of the root finding algorithm
+#[derive(Clone, Copy, Debug)]
+pub struct RootFindingOptions {
+    /// The iterations stop when every correction is below this tolerance, relative to the root modulus
+    pub tolerance: f64,
+    /// Maximum number of Aberth-Ehrlich iterations
+    pub max_iterations: usize,
+    /// Approximations closer than this distance, relative to their modulus, are merged into a multiple root
+    pub multiplicity_tolerance: f64,
+    /// Number of Newton iterations applied on each root after convergence
+    pub polishing_iterations: usize,
+}
+
+impl Default for RootFindingOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-12,
+            max_iterations: 500,
+            multiplicity_tolerance: 1e-4,
+            polishing_iterations: 3,
+        }
+    }
+}
+
+/// A root of a polynomial, with its multiplicity and an estimate of the distance to the exact root
+#[derive(Clone, Copy, Debug)]
+pub struct Root {
+    pub value: RootComplex,
+    pub multiplicity: usize,
+    pub error: f64,
+}
+
+impl Polynomial<RootComplex> {
+    /// Computes all complex roots of the polynomial, grouped with their multiplicity.
+    ///
+    /// The sum of multiplicities is the degree of the polynomial. This returns an error for the zero polynomial,
+    /// or if the approximations didn't converge in the allowed number of iterations.
+    pub fn roots(&self, options: &RootFindingOptions) -> Result<Vec<Root>, PolynomialError> {
+        let degree = match self.degree() {
+            Some(deg) => deg,
+            None => {
+                return Err(PolynomialError::ZeroPolynomial(
+                    "The zero polynomial has no finite set of roots".to_string(),
+                ))
+            }
+        };
+        if degree == 0 {
+            return Ok(vec![]);
+        }
+
+        // Working on the monic version of the polynomial keeps the values in a reasonable range
+        let lead = self.coefs[degree];
+        let monic = Polynomial::new(
+            &self.coefs[..=degree]
+                .iter()
+                .map(|c| *c / lead)
+                .collect::<Vec<RootComplex>>(),
+        );
+        let derivative = monic.derivative();
+
+        let mut approximations = Self::initial_approximations(&monic);
+        Self::aberth_ehrlich(&monic, &derivative, &mut approximations, options)?;
+
+        Ok(
+            Self::cluster(&approximations, options.multiplicity_tolerance)
+                .into_iter()
+                .map(|(value, multiplicity)| Self::polish(&monic, value, multiplicity, options))
+                .collect(),
+        )
+    }
+
+    /// Initial approximations are spread on a circle centered on the roots barycenter, with a radius
+    /// bounding the roots modulus. The angle offset avoids symmetries of real polynomials.
+    fn initial_approximations(monic: &Polynomial<RootComplex>) -> Vec<RootComplex> {
+        let degree = monic.coefs.len() - 1;
+        let degree_f = degree as f64;
+        let center = -monic.coefs[degree - 1] / RootComplex::from(degree_f);
+
+        // Fujiwara bound on the roots modulus
+        let mut radius: f64 = 0.0;
+        for i in 1..=degree {
+            radius = radius.max(monic.coefs[degree - i].abs().powf(1.0 / i as f64));
+        }
+        radius = f64::max(2.0 * radius, f64::MIN_POSITIVE);
+
+        (0..degree)
+            .map(|k| {
+                let angle = 2.0 * std::f64::consts::PI * (k as f64) / degree_f + 0.4;
+                center + RootComplex::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect()
+    }
+
+    /// Aberth-Ehrlich iterations, each approximation being updated in place as soon as its correction is known
+    fn aberth_ehrlich(
+        monic: &Polynomial<RootComplex>,
+        derivative: &Polynomial<RootComplex>,
+        approximations: &mut [RootComplex],
+        options: &RootFindingOptions,
+    ) -> Result<(), PolynomialError> {
+        let zero = RootComplex::from(0.0);
+        let one = RootComplex::from(1.0);
+
+        // Bound on the rounding error of the evaluation: below it, the value of the polynomial is meaningless
+        let abs_coefs = Polynomial::new(&monic.coefs.iter().map(|c| c.abs()).collect::<Vec<f64>>());
+        let rounding = 2.0 * (monic.coefs.len() as f64) * f64::EPSILON;
+
+        for _ in 0..options.max_iterations {
+            let mut converged = true;
+            for k in 0..approximations.len() {
+                let z = approximations[k];
+                let value = monic.apply(z);
+                if value == zero {
+                    continue;
+                }
+
+                let newton = value / derivative.apply(z);
+                let mut repulsion = zero;
+                for (j, &other) in approximations.iter().enumerate() {
+                    if j != k {
+                        repulsion += one / (z - other);
+                    }
+                }
+                let correction = newton / (one - newton * repulsion);
+
+                // A non finite correction means the approximation sits on another one, or on a root of
+                // the derivative: it is kept for this iteration and will move along with the others
+                let small_enough = correction.abs() <= options.tolerance * (1.0 + z.abs())
+                    || value.abs() <= rounding * abs_coefs.apply(z.abs());
+                if correction.abs().is_finite() {
+                    approximations[k] = z - correction;
+                }
+                if !small_enough {
+                    converged = false;
+                }
+            }
+            if converged {
+                return Ok(());
+            }
+        }
+
+        Err(PolynomialError::NotConverged(format!(
+            "Root finding did not converge in {} iterations",
+            options.max_iterations
+        )))
+    }
+
+    /// Groups approximations closer than the tolerance, and returns the barycenter of each group with its size
+    fn cluster(approximations: &[RootComplex], tolerance: f64) -> Vec<(RootComplex, usize)> {
+        let mut clusters = Vec::<(RootComplex, usize)>::new();
+        let mut assigned = vec![false; approximations.len()];
+
+        for k in 0..approximations.len() {
+            if assigned[k] {
+                continue;
+            }
+            let mut sum = approximations[k];
+            let mut size = 1;
+            for j in k + 1..approximations.len() {
+                let distance = (approximations[j] - approximations[k]).abs();
+                if !assigned[j] && distance <= tolerance * (1.0 + approximations[k].abs()) {
+                    assigned[j] = true;
+                    sum += approximations[j];
+                    size += 1;
+                }
+            }
+            clusters.push((sum / RootComplex::from(size as f64), size));
+        }
+
+        clusters
+    }
+
+    /// Newton iterations for a root of known multiplicity m. A root of multiplicity m is a simple root of the
+    /// (m - 1)-th derivative, on which Newton iterations converge quadratically.
+    /// The error estimate is the size of the last Newton correction.
+    fn polish(
+        monic: &Polynomial<RootComplex>,
+        mut value: RootComplex,
+        multiplicity: usize,
+        options: &RootFindingOptions,
+    ) -> Root {
+        let mut function = monic.clone();
+        for _ in 1..multiplicity {
+            function = function.derivative();
+        }
+        let derivative = function.derivative();
+
+        let mut error = 0.0;
+        for iteration in 0..=options.polishing_iterations {
+            let correction = function.apply(value) / derivative.apply(value);
+            if !correction.abs().is_finite() {
+                break;
+            }
+            error = correction.abs();
+            if error == 0.0 || iteration == options.polishing_iterations {
+                break;
+            }
+            value -= correction;
+        }
+
+        Root {
+            value,
+            multiplicity,
+            error,
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! This module implements truncated power series arithmetic: series are polynomials mod(x^order).
+//!
+//! Inverse, logarithm, exponential and square root are computed with Newton iteration, which doubles the number of
+//! correct coefficients at each step. Since every step is made of fft-based products, these operations cost
+//! O(nlog(n)).
+
+use crate::algebra::{CommutativeRing, Field};
+use crate::polynomial::convolution::FastConvolution;
+use crate::polynomial::division::truncated_product;
+use crate::polynomial::Polynomial;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Power series error types
+#[derive(Debug)]
+pub enum PowerSeriesError {
+    OrderMismatched(String),
+    DegreeAboveOrder(String),
+    InvalidConstantTerm(String),
+    NonInvertibleInteger(String),
+}
+type PowerSeriesResult<T> = Result<PowerSeries<T>, PowerSeriesError>;
+
+/// Type representing a power series truncated mod(x^order).
+/// The coefs Vec inside polynomial must have length order.
+#[derive(Clone)]
+pub struct PowerSeries<T: CommutativeRing> {
+    polynomial: Polynomial<T>,
+}
+
+/// Implement the Display trait
+impl<T: CommutativeRing> std::fmt::Display for PowerSeries<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.polynomial.to_string())
+    }
+}
+
+impl<T: CommutativeRing> PowerSeries<T> {
+    /// Creates a power series from the first coefficients of the polynomial.
+    /// Missing coefficients are zeros, and coefficients of degree above the order are dropped.
+    pub fn new(poly: &Polynomial<T>, order: usize) -> Self {
+        let mut polynomial = poly.clone();
+        polynomial.coefs.resize(order, T::zero());
+        Self { polynomial }
+    }
+
+    /// Number of coefficients of the series: it is known mod(x^order)
+    pub fn order(&self) -> usize {
+        self.polynomial.coefs.len()
+    }
+
+    /// Changes the order of the series: coefficients above the new order are dropped, and a higher order
+    /// adds zero coefficients
+    pub fn with_order(&self, order: usize) -> Self {
+        Self::new(&self.polynomial, order)
+    }
+
+    /// Public getter for the underlying polynomial
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Calls the underlying polynomial call function
+    pub fn apply(&self, x: T) -> T {
+        self.polynomial.apply(x)
+    }
+
+    /// Check coefficient access
+    fn check_coef(&self, n: usize) -> Result<(), PowerSeriesError> {
+        if n >= self.order() {
+            return Err(PowerSeriesError::DegreeAboveOrder(format!(
+                "Degree {} higher than or equal to order {}",
+                n,
+                self.order()
+            )));
+        }
+        Ok(())
+    }
+    /// Public getter for a coef
+    pub fn coef(&self, n: usize) -> Result<T, PowerSeriesError> {
+        self.check_coef(n)?;
+        Ok(self.polynomial.coef(n))
+    }
+    /// Public setter for a coef
+    pub fn coef_mut(&mut self, n: usize) -> Result<&mut T, PowerSeriesError> {
+        self.check_coef(n)?;
+        Ok(self.polynomial.coef_mut(n))
+    }
+
+    /// Check the order of another series against this one
+    fn check_order(&self, other: &PowerSeries<T>) -> Result<(), PowerSeriesError> {
+        if self.order() != other.order() {
+            return Err(PowerSeriesError::OrderMismatched(format!(
+                "Order mismatch: {}, {}",
+                self.order(),
+                other.order()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the constant term of the series, required by some operations
+    fn check_constant_term(&self, expected: T, operation: &str) -> Result<(), PowerSeriesError> {
+        match self.polynomial.coefs.first() {
+            Some(&c) if c != expected => Err(PowerSeriesError::InvalidConstantTerm(format!(
+                "{} needs a constant term equal to {}, got {}",
+                operation, expected, c
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: FastConvolution + Field,
+{
+    /// Multiplicative inverse, which exists as soon as the constant term is non-zero
+    pub fn inverse(&self) -> PowerSeriesResult<T> {
+        if self.order() == 0 {
+            return Ok(self.clone());
+        }
+        if self.polynomial.coefs[0] == T::zero() {
+            return Err(PowerSeriesError::InvalidConstantTerm(
+                "Inverse needs a non-zero constant term".to_string(),
+            ));
+        }
+        Ok(Self {
+            polynomial: Polynomial {
+                coefs: Polynomial::reciprocal(&self.polynomial.coefs, self.order()),
+            },
+        })
+    }
+
+    /// Derivative of the series. The coefficient of highest degree is unknown, and set to zero.
+    pub fn derivative(&self) -> PowerSeries<T> {
+        PowerSeries::new(&self.polynomial.derivative(), self.order())
+    }
+
+    /// Primitive of the series with a zero constant term. The coefficient of highest degree is dropped.
+    ///
+    /// It divides by every degree below the order, which fails in a prime field whose characteristic is lower.
+    pub fn integral(&self) -> PowerSeriesResult<T> {
+        let mut coefs = vec![T::zero(); self.order()];
+        for (deg, coef) in coefs.iter_mut().enumerate().skip(1) {
+            let divisor = T::from_integer(deg as i64);
+            if divisor == T::zero() {
+                return Err(PowerSeriesError::NonInvertibleInteger(format!(
+                    "Integral needs to divide by {}, which is zero in the coefficient field",
+                    deg
+                )));
+            }
+            *coef = self.polynomial.coefs[deg - 1] / divisor;
+        }
+        Ok(Self {
+            polynomial: Polynomial { coefs },
+        })
+    }
+
+    /// Natural logarithm of a series with constant term 1, computed as the primitive of f' / f
+    pub fn ln(&self) -> PowerSeriesResult<T> {
+        self.check_constant_term(T::one(), "Logarithm")?;
+        let quotient = truncated_product(
+            &self.derivative().polynomial.coefs,
+            &self.inverse()?.polynomial.coefs,
+            self.order(),
+        );
+        Self {
+            polynomial: Polynomial { coefs: quotient },
+        }
+        .integral()
+    }
+
+    /// Exponential of a series with constant term 0, via Newton iteration: g <- g * (1 - ln(g) + f)
+    pub fn exp(&self) -> PowerSeriesResult<T> {
+        self.check_constant_term(T::zero(), "Exponential")?;
+        if self.order() == 0 {
+            return Ok(self.clone());
+        }
+
+        let mut exp = PowerSeries::new(&Polynomial::new(&[T::one()]), 1);
+        let mut precision = 1;
+        while precision < self.order() {
+            precision = usize::min(2 * precision, self.order());
+            exp = exp.with_order(precision);
+
+            let mut correction = PowerSeries::new(&self.polynomial, precision);
+            correction.polynomial -= &exp.ln()?.polynomial;
+            correction.polynomial.coefs[0] += T::one();
+
+            exp.polynomial.coefs = truncated_product(
+                &exp.polynomial.coefs,
+                &correction.polynomial.coefs,
+                precision,
+            );
+            // The constant term is exactly 1, but the fft-based products only give it up to rounding errors, and
+            // the logarithm of the next step needs it exact
+            exp.polynomial.coefs[0] = T::one();
+        }
+        Ok(exp)
+    }
+
+    /// Square root of a series with constant term 1, via Newton iteration: g <- (g + f / g) / 2
+    pub fn sqrt(&self) -> PowerSeriesResult<T> {
+        self.check_constant_term(T::one(), "Square root")?;
+        if self.order() == 0 {
+            return Ok(self.clone());
+        }
+
+        let half = T::one() / T::from_integer(2);
+        let mut sqrt = PowerSeries::new(&Polynomial::new(&[T::one()]), 1);
+        let mut precision = 1;
+        while precision < self.order() {
+            precision = usize::min(2 * precision, self.order());
+            sqrt = sqrt.with_order(precision);
+
+            let quotient = truncated_product(
+                &self.polynomial.coefs[..precision],
+                &sqrt.inverse()?.polynomial.coefs,
+                precision,
+            );
+            for (coef, &q) in sqrt.polynomial.coefs.iter_mut().zip(quotient.iter()) {
+                *coef = (*coef + q) * half;
+            }
+        }
+        Ok(sqrt)
+    }
+
+    /// Composition f(g(x)) of this series f with a series g of constant term 0, computed with Horner's scheme
+    pub fn compose(&self, inner: &PowerSeries<T>) -> PowerSeriesResult<T> {
+        self.check_order(inner)?;
+        inner.check_constant_term(T::zero(), "Composition")?;
+
+        let mut ret = vec![T::zero(); self.order()];
+        for &c in self.polynomial.coefs.iter().rev() {
+            ret = truncated_product(&ret, &inner.polynomial.coefs, self.order());
+            if let Some(first) = ret.first_mut() {
+                *first += c;
+            }
+        }
+        Ok(Self {
+            polynomial: Polynomial { coefs: ret },
+        })
+    }
+}
+
+/// The Add operation for power series references.
+///
+/// This returns a Result because there potentially could be a mismatch of orders between the two series.
+impl<'a, T: CommutativeRing> Add for &'a PowerSeries<T> {
+    type Output = PowerSeriesResult<T>;
+
+    fn add(self, other: &'a PowerSeries<T>) -> PowerSeriesResult<T> {
+        self.check_order(other)?;
+        Ok(PowerSeries {
+            polynomial: &self.polynomial + &other.polynomial,
+        })
+    }
+}
+
+/// The Sub operation for power series references.
+///
+/// This returns a Result because there potentially could be a mismatch of orders between the two series.
+impl<'a, T: CommutativeRing> Sub for &'a PowerSeries<T> {
+    type Output = PowerSeriesResult<T>;
+
+    fn sub(self, other: &'a PowerSeries<T>) -> PowerSeriesResult<T> {
+        self.check_order(other)?;
+        Ok(PowerSeries {
+            polynomial: &self.polynomial - &other.polynomial,
+        })
+    }
+}
+
+/// The Neg operation for power series references.
+impl<T: CommutativeRing> Neg for &PowerSeries<T> {
+    type Output = PowerSeries<T>;
+
+    fn neg(self) -> PowerSeries<T> {
+        PowerSeries {
+            polynomial: -&self.polynomial,
+        }
+    }
+}
+
+/// The Mul operation for power series references: the product is truncated to the order of the series.
+///
+/// This returns a Result because there potentially could be a mismatch of orders between the two series.
+impl<'a, T> Mul for &'a PowerSeries<T>
+where
+    T: FastConvolution,
+{
+    type Output = PowerSeriesResult<T>;
+
+    fn mul(self, other: &'a PowerSeries<T>) -> PowerSeriesResult<T> {
+        self.check_order(other)?;
+        Ok(PowerSeries {
+            polynomial: Polynomial {
+                coefs: truncated_product(
+                    &self.polynomial.coefs,
+                    &other.polynomial.coefs,
+                    self.order(),
+                ),
+            },
+        })
+    }
+}
@@ -386,3 +386,1735 @@ fn convolution_via_fft_test() {
         assert!(nearly_equal_f32(conv_fft[i], conv_classic[i]));
     }
 }
+
+use crate::polynomial::RingKind;
+
+#[test]
+fn negacyclic_mod_polynomial() {
+    let mono_2 = Polynomial::new_monomial(1.0, 2);
+    let mono_3 = Polynomial::new_monomial(1.0, 3);
+    let mono_5 = Polynomial::new_monomial(1.0, 5);
+    let mono_6 = Polynomial::new_monomial(1.0, 6);
+
+    let mut mod_poly = ModularArithmeticPolynomial::new_negacyclic(&mono_2, 3);
+    assert_eq!(mod_poly.kind(), RingKind::Negacyclic);
+    assert_eq!(mod_poly.polynomial.coefs.len(), mod_poly.modulus());
+    assert_eq!(mod_poly.apply(2.0), 4.0);
+
+    mod_poly = ModularArithmeticPolynomial::new_negacyclic(&mono_3, 3);
+    assert_eq!(mod_poly.apply(2.0), -1.0);
+
+    mod_poly = ModularArithmeticPolynomial::new_negacyclic(&mono_5, 3);
+    assert_eq!(mod_poly.apply(2.0), -4.0);
+
+    mod_poly = ModularArithmeticPolynomial::new_negacyclic(&mono_6, 3);
+    assert_eq!(mod_poly.apply(2.0), 1.0);
+}
+
+#[test]
+fn mult_negacyclic_polynomial() {
+    // P1(x) = 1 + 2x + x²
+    // P2(x) = 1 + x + 2x²
+    // P1 * P2 = 1 + 3x + 5x² + 5x³ + 2x⁴ = -4 + x + 5x² mod(x³ + 1)
+    let mod_poly_1 =
+        ModularArithmeticPolynomial::<f64>::new_negacyclic(&Polynomial::new(&[1.0, 2.0, 1.0]), 3);
+    let mod_poly_2 =
+        ModularArithmeticPolynomial::<f64>::new_negacyclic(&Polynomial::new(&[1.0, 1.0, 2.0]), 3);
+
+    let prod = (&mod_poly_1 * &mod_poly_2).expect("");
+    assert_eq!(prod.kind(), RingKind::Negacyclic);
+    assert_eq!(prod.polynomial.coefs, vec![-4.0, 1.0, 5.0]);
+}
+
+#[test]
+fn mult_negacyclic_polynomial_fft() {
+    // x * x^(N - 1) = x^N = -1 mod(x^N + 1)
+    const N: usize = 200;
+
+    let mod_poly_1 =
+        ModularArithmeticPolynomial::<f64>::new_negacyclic(&Polynomial::new_monomial(1.0, 1), N);
+    let mod_poly_2 = ModularArithmeticPolynomial::<f64>::new_negacyclic(
+        &Polynomial::new_monomial(1.0, N - 1),
+        N,
+    );
+
+    let prod = (&mod_poly_1 * &mod_poly_2).expect("");
+    assert!((prod.coef(0).unwrap() + 1.0).abs() < 1e-10);
+    for i in 1..N {
+        assert!(prod.coef(i).unwrap().abs() < 1e-10);
+    }
+}
+
+#[test]
+fn add_negacyclic_polynomial_error() {
+    let mono_2 = Polynomial::new_monomial(1.0, 2);
+
+    let mod_poly_1 = ModularArithmeticPolynomial::new(&mono_2, 3);
+    let mod_poly_2 = ModularArithmeticPolynomial::new_negacyclic(&mono_2, 3);
+
+    match &mod_poly_1 + &mod_poly_2 {
+        Err(ModularArithmeticError::RingKindMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match &mod_poly_1 * &mod_poly_2 {
+        Err(ModularArithmeticError::RingKindMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn checked_assign_error() {
+    let mono_2 = Polynomial::new_monomial(1.0, 2);
+    let mut mod_poly_1 = ModularArithmeticPolynomial::new(&mono_2, 3);
+    let mod_poly_2 = ModularArithmeticPolynomial::new(&mono_2, 4);
+
+    match mod_poly_1.checked_add_assign(&mod_poly_2) {
+        Err(ModularArithmeticError::ModulusMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match mod_poly_1.checked_sub_assign(&mod_poly_2) {
+        Err(ModularArithmeticError::ModulusMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+
+    // Left unchanged by the failed operations
+    assert_eq!(mod_poly_1.coef(2).unwrap(), 1.0);
+    mod_poly_1
+        .checked_add_assign(&ModularArithmeticPolynomial::new(&mono_2, 3))
+        .unwrap();
+    assert_eq!(mod_poly_1.coef(2).unwrap(), 2.0);
+}
+
+use crate::polynomial::QuotientRingPolynomial;
+
+#[test]
+fn quotient_ring_polynomial() {
+    // x³ mod(x² + 1) = -x
+    let modulus = Polynomial::new(&[1.0, 0.0, 1.0, 0.0]);
+    let poly = QuotientRingPolynomial::new(&Polynomial::new_monomial(1.0, 3), &modulus).unwrap();
+
+    assert_eq!(poly.modulus().degree(), Some(2));
+    assert_eq!(poly.coef(0).unwrap(), 0.0);
+    assert_eq!(poly.coef(1).unwrap(), -1.0);
+    match poly.coef(2) {
+        Err(ModularArithmeticError::DegreeAboveModulus(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn quotient_ring_polynomial_not_monic() {
+    let modulus = Polynomial::new(&[1.0, 0.0, 2.0]);
+
+    match QuotientRingPolynomial::new(&Polynomial::new_monomial(1.0, 3), &modulus) {
+        Err(ModularArithmeticError::ModulusNotMonic(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn mult_quotient_ring_polynomial() {
+    // (1 + 2x) * (3 + x) = 3 + 7x + 2x² = 1 + 7x mod(x² + 1)
+    let modulus = Polynomial::new(&[1.0, 0.0, 1.0]);
+    let poly_1 =
+        QuotientRingPolynomial::<f64>::new(&Polynomial::new(&[1.0, 2.0]), &modulus).unwrap();
+    let poly_2 =
+        QuotientRingPolynomial::<f64>::new(&Polynomial::new(&[3.0, 1.0]), &modulus).unwrap();
+
+    let prod = (&poly_1 * &poly_2).unwrap();
+    assert_eq!(prod.coef(0).unwrap(), 1.0);
+    assert_eq!(prod.coef(1).unwrap(), 7.0);
+
+    let sum = (&poly_1 + &poly_2).unwrap();
+    assert_eq!(sum.apply(1.0), 7.0);
+    let diff = (&poly_1 - &poly_2).unwrap();
+    assert_eq!(diff.apply(1.0), -1.0);
+    assert_eq!((-&diff).apply(1.0), 1.0);
+}
+
+#[test]
+fn mult_quotient_ring_matches_cyclic_ring() {
+    // mod(x³ - 1) is the same ring as the modular arithmetic with modulus 3
+    let modulus = Polynomial::new(&[-1.0, 0.0, 0.0, 1.0]);
+    let p1 = Polynomial::new(&[1.0, 2.0, 1.0]);
+    let p2 = Polynomial::new(&[1.0, 1.0, 2.0]);
+
+    let quotient_prod = (&QuotientRingPolynomial::<f64>::new(&p1, &modulus).unwrap()
+        * &QuotientRingPolynomial::<f64>::new(&p2, &modulus).unwrap())
+        .unwrap();
+    let modular_prod = (&ModularArithmeticPolynomial::<f64>::new(&p1, 3)
+        * &ModularArithmeticPolynomial::<f64>::new(&p2, 3))
+        .unwrap();
+
+    for i in 0..3 {
+        assert_eq!(
+            quotient_prod.coef(i).unwrap(),
+            modular_prod.coef(i).unwrap()
+        );
+    }
+}
+
+#[test]
+fn add_quotient_ring_polynomial_error() {
+    let poly = Polynomial::new(&[1.0, 2.0]);
+    let poly_1 = QuotientRingPolynomial::new(&poly, &Polynomial::new(&[1.0, 0.0, 1.0])).unwrap();
+    let poly_2 = QuotientRingPolynomial::new(&poly, &Polynomial::new(&[2.0, 0.0, 1.0])).unwrap();
+
+    match &poly_1 + &poly_2 {
+        Err(ModularArithmeticError::ModulusPolynomialMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match &poly_1 * &poly_2 {
+        Err(ModularArithmeticError::ModulusPolynomialMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+use crate::polynomial::PolynomialError;
+
+#[test]
+fn div_rem_polynomial() {
+    // x³ + 2x² + 3x + 4 = (x + 1) * (x² + x + 2) + 2
+    let a = Polynomial::<f64>::new(&[4.0, 3.0, 2.0, 1.0]);
+    let b = Polynomial::<f64>::new(&[1.0, 1.0, 0.0]);
+
+    let (q, r) = a.div_rem(&b).unwrap();
+    assert_eq!(q.coefs, vec![2.0, 1.0, 1.0]);
+    assert_eq!(r.coefs, vec![2.0]);
+
+    assert_eq!((&a / &b).unwrap().coefs, q.coefs);
+    assert_eq!((&a % &b).unwrap().coefs, r.coefs);
+
+    // Dividing by a polynomial of higher degree leaves the dividend as remainder
+    let (q, r) = b.div_rem(&a).unwrap();
+    assert_eq!(q.degree(), None);
+    assert_eq!(r.coefs, vec![1.0, 1.0]);
+}
+
+#[test]
+fn div_rem_polynomial_by_zero() {
+    let a = Polynomial::<f64>::new(&[4.0, 3.0]);
+    let b = Polynomial::<f64>::new(&[0.0, 0.0]);
+
+    match a.div_rem(&b) {
+        Err(PolynomialError::DivisionByZero(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match &a / &Polynomial::new(&[]) {
+        Err(PolynomialError::DivisionByZero(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn div_rem_polynomial_newton() {
+    const DIVISOR_DEG: usize = 200;
+    const QUOTIENT_DEG: usize = 300;
+
+    let mut b = Polynomial::new_monomial(1.0, DIVISOR_DEG);
+    let mut q = Polynomial::new_monomial(1.0, QUOTIENT_DEG);
+    let mut r = Polynomial::new(&vec![0.0; DIVISOR_DEG]);
+    for i in 0..DIVISOR_DEG {
+        *b.coef_mut(i) = ((i % 7) as f64) * 0.001;
+        *r.coef_mut(i) = (i % 3) as f64;
+    }
+    for i in 0..QUOTIENT_DEG {
+        *q.coef_mut(i) = (i % 5) as f64;
+    }
+
+    let mut a = Polynomial::new(&crate::polynomial::division::truncated_product(
+        &b.coefs,
+        &q.coefs,
+        DIVISOR_DEG + QUOTIENT_DEG + 1,
+    ));
+    a += &r;
+
+    let (fast_q, fast_r) = Polynomial::fast_div_rem(&a, &b);
+    let (long_q, long_r) = Polynomial::long_div_rem(&a, &b);
+    assert_eq!(fast_q.coefs.len(), QUOTIENT_DEG + 1);
+    assert_eq!(fast_r.coefs.len(), DIVISOR_DEG);
+    for i in 0..=QUOTIENT_DEG {
+        assert!((fast_q.coef(i) - q.coef(i)).abs() < 1e-6);
+        assert!((long_q.coef(i) - q.coef(i)).abs() < 1e-6);
+    }
+    for i in 0..DIVISOR_DEG {
+        assert!((fast_r.coef(i) - r.coef(i)).abs() < 1e-6);
+        assert!((long_r.coef(i) - r.coef(i)).abs() < 1e-6);
+    }
+
+    let (q_2, r_2) = a.div_rem(&b).unwrap();
+    assert_eq!(q_2.degree(), Some(QUOTIENT_DEG));
+    assert!(r_2.degree().unwrap() < DIVISOR_DEG);
+}
+
+#[test]
+fn gcd_polynomial() {
+    // (x - 1)(x - 2) and (x - 1)(x + 3)
+    let a = Polynomial::<f64>::new(&[2.0, -3.0, 1.0]);
+    let b = Polynomial::<f64>::new(&[-6.0, 4.0, 2.0]);
+
+    let g = a.gcd(&b, 1e-10);
+    assert_eq!(g.coefs.len(), 2);
+    assert!((g.coef(0) + 1.0).abs() < 1e-10);
+    assert!((g.coef(1) - 1.0).abs() < 1e-10);
+
+    // Coprime polynomials have a constant gcd
+    let c = Polynomial::<f64>::new(&[3.0, 1.0]);
+    assert_eq!(a.gcd(&c, 1e-10).coefs, vec![1.0]);
+
+    // The zero polynomial is divisible by anything
+    let zero = Polynomial::<f64>::new(&[0.0]);
+    assert_eq!(zero.gcd(&c, 1e-10).coefs, vec![3.0, 1.0]);
+}
+
+#[test]
+fn extended_gcd_polynomial() {
+    let a = Polynomial::<f64>::new(&[1.0, 2.0, 0.0, 1.0]);
+    let b = Polynomial::<f64>::new(&[-1.0, 0.0, 1.0]);
+
+    let (g, s, t) = a.extended_gcd(&b, 1e-10);
+    for x in [-2.0, 0.5, 3.0] {
+        let bezout = s.apply(x) * a.apply(x) + t.apply(x) * b.apply(x);
+        assert!((bezout - g.apply(x)).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn inverse_mod_polynomial() {
+    // 2 + x is invertible mod(x³ - 1) since -2 is not a cubic root of unity
+    let mod_poly = ModularArithmeticPolynomial::<f64>::new(&Polynomial::new(&[2.0, 1.0]), 3);
+    let inverse = mod_poly.inverse(1e-10).unwrap();
+    let prod = (&mod_poly * &inverse).unwrap();
+    assert!((prod.coef(0).unwrap() - 1.0).abs() < 1e-10);
+    assert!(prod.coef(1).unwrap().abs() < 1e-10);
+    assert!(prod.coef(2).unwrap().abs() < 1e-10);
+
+    // 1 + x is invertible mod(x² + 1), with inverse (1 - x) / 2
+    let mod_poly =
+        ModularArithmeticPolynomial::<f64>::new_negacyclic(&Polynomial::new(&[1.0, 1.0]), 2);
+    let inverse = mod_poly.inverse(1e-10).unwrap();
+    assert_eq!(inverse.kind(), RingKind::Negacyclic);
+    assert!((inverse.coef(0).unwrap() - 0.5).abs() < 1e-10);
+    assert!((inverse.coef(1).unwrap() + 0.5).abs() < 1e-10);
+}
+
+#[test]
+fn inverse_mod_polynomial_prime_field() {
+    type F = Zp<P_998244353>;
+    let coefs: Vec<F> = [3u64, 1, 4, 1, 5, 9, 2, 6]
+        .iter()
+        .map(|&c| F::new(c))
+        .collect();
+    for mod_poly in [
+        ModularArithmeticPolynomial::new(&Polynomial::new(&coefs), 8),
+        ModularArithmeticPolynomial::new_negacyclic(&Polynomial::new(&coefs), 8),
+    ] {
+        let inverse = mod_poly.inverse(0.0).unwrap();
+        let prod = (&mod_poly * &inverse).unwrap();
+        assert_eq!(prod.coef(0).unwrap(), F::new(1));
+        for i in 1..8 {
+            assert_eq!(prod.coef(i).unwrap(), F::new(0));
+        }
+    }
+
+    // 1 - x divides x^8 - 1
+    let mod_poly = ModularArithmeticPolynomial::new(&Polynomial::new(&[F::new(1), -F::new(1)]), 8);
+    match mod_poly.inverse(0.0) {
+        Err(ModularArithmeticError::NotInvertible(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn inverse_mod_polynomial_error() {
+    // 1 - x divides x³ - 1
+    let mod_poly = ModularArithmeticPolynomial::<f64>::new(&Polynomial::new(&[1.0, -1.0]), 3);
+
+    match mod_poly.inverse(1e-10) {
+        Err(ModularArithmeticError::NotInvertible(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn sub_polynomial_different_sizes() {
+    let poly_1 = Polynomial::new(&[1.0, 2.0, 3.0]);
+    let poly_2 = Polynomial::new(&[1.0]);
+
+    assert_eq!((&poly_1 - &poly_2).coefs, vec![0.0, 2.0, 3.0]);
+    assert_eq!((&poly_2 - &poly_1).coefs, vec![0.0, -2.0, -3.0]);
+}
+
+#[test]
+fn mult_polynomial() {
+    // (1 + 2x + x²) * (1 - x) = 1 + x - x² - x³
+    let poly_1 = Polynomial::<f64>::new(&[1.0, 2.0, 1.0]);
+    let poly_2 = Polynomial::<f64>::new(&[1.0, -1.0]);
+
+    assert_eq!((&poly_1 * &poly_2).coefs, vec![1.0, 1.0, -1.0, -1.0]);
+    assert_eq!((&poly_2 * &poly_1).coefs, vec![1.0, 1.0, -1.0, -1.0]);
+    assert_eq!((&poly_1 * &Polynomial::new(&[])).degree(), None);
+}
+
+#[test]
+fn mult_polynomial_all_sizes() {
+    // Covers the naive, Karatsuba and fft-based convolutions, with balanced and unbalanced sizes
+    for (size_1, size_2) in [
+        (10, 20),
+        (50, 50),
+        (40, 120),
+        (100, 33),
+        (200, 150),
+        (500, 40),
+        (1500, 1100),
+    ] {
+        let poly_1 = Polynomial::<f64>::new(
+            &(0..size_1)
+                .map(|i| (i % 7) as f64 - 3.0)
+                .collect::<Vec<f64>>(),
+        );
+        let poly_2 = Polynomial::<f64>::new(
+            &(0..size_2)
+                .map(|i| (i % 5) as f64 - 2.0)
+                .collect::<Vec<f64>>(),
+        );
+
+        let prod = &poly_1 * &poly_2;
+        let expected = naive_linear_convolution(&poly_1.coefs, &poly_2.coefs);
+        assert_eq!(prod.coefs.len(), size_1 + size_2 - 1);
+        for (val, expected_val) in prod.coefs.iter().zip(expected.iter()) {
+            assert!((val - expected_val).abs() < 1e-8);
+        }
+    }
+}
+
+use crate::polynomial::convolution::{karatsuba_convolution, naive_linear_convolution};
+
+#[test]
+fn karatsuba_convolution_test() {
+    const N: usize = 100;
+
+    let a: Vec<f64> = (0..N).map(|i| i as f64).collect();
+    let b: Vec<f64> = (0..N).map(|i| (N - i) as f64).collect();
+
+    assert_eq!(naive_convolution(&a, &b), karatsuba_convolution(&a, &b));
+}
+
+use crate::polynomial::convolution::{
+    convolution, linear_convolution, toom3_convolution, toom3_linear_convolution,
+};
+
+fn pseudo_random_i64(size: usize, seed: i64) -> Vec<i64> {
+    (0..size as i64)
+        .map(|n| (n * 7919 + seed * 104729) % 2001 - 1000)
+        .collect()
+}
+
+#[test]
+fn toom3_convolution_integers() {
+    // Sizes which are not multiples of 3, with several levels of recursion
+    for size in [129, 200, 500, 1000] {
+        let a = pseudo_random_i64(size, 1);
+        let b = pseudo_random_i64(size, 2);
+        assert_eq!(toom3_convolution(&a, &b), naive_linear_convolution(&a, &b));
+    }
+
+    let a = pseudo_random_i64(300, 3);
+    let b = pseudo_random_i64(1000, 4);
+    assert_eq!(
+        toom3_linear_convolution(&a, &b),
+        naive_linear_convolution(&a, &b)
+    );
+}
+
+#[test]
+fn convolution_large_integers() {
+    // 25 bits coefficients: the product fits in i64, but not the intermediate values of a Toom-3 convolution in i64
+    let large = (1i64 << 25) - 1;
+    let inputs = [
+        (vec![large; 600], vec![large; 600]),
+        (vec![large; 600], vec![-large; 600]),
+        (
+            pseudo_random_i64(600, 13)
+                .iter()
+                .map(|x| x * 33_000)
+                .collect(),
+            pseudo_random_i64(600, 14)
+                .iter()
+                .map(|x| x * 33_000)
+                .collect(),
+        ),
+    ];
+    for (a, b) in inputs {
+        let expected = naive_linear_convolution(&a, &b);
+        assert_eq!(linear_convolution(&a, &b), expected);
+        assert_eq!(
+            (&Polynomial::new(&a) * &Polynomial::new(&b)).coefs,
+            expected
+        );
+        assert_eq!(
+            convolution(&a, &b),
+            convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b)
+        );
+    }
+
+    let a = vec![(1i128 << 50) - 1; 600];
+    let b = vec![1 - (1i128 << 50); 600];
+    assert_eq!(linear_convolution(&a, &b), naive_linear_convolution(&a, &b));
+}
+
+#[test]
+fn toom3_convolution_prime_field() {
+    let a = pseudo_random_zp::<P_998244353>(400, 5);
+    let b = pseudo_random_zp::<P_998244353>(400, 6);
+    assert_eq!(toom3_convolution(&a, &b), naive_linear_convolution(&a, &b));
+
+    // 3 isn't invertible modulo 3: this falls back to Karatsuba
+    let a = pseudo_random_zp::<3>(300, 7);
+    let b = pseudo_random_zp::<3>(300, 8);
+    assert_eq!(toom3_convolution(&a, &b), naive_linear_convolution(&a, &b));
+}
+
+#[test]
+fn toom3_convolution_floats() {
+    let a: Vec<f64> = (0..400).map(|n| (n as f64 * 0.37).sin()).collect();
+    let b: Vec<f64> = (0..400).map(|n| (n as f64 * 0.91).cos()).collect();
+    for (x, y) in toom3_convolution(&a, &b)
+        .iter()
+        .zip(naive_linear_convolution(&a, &b).iter())
+    {
+        assert!((x - y).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn convolution_dispatch_tiers() {
+    // Sizes going through each algorithm of the integer thresholds
+    for size in [16, 100, 200, 300, 600] {
+        let a = pseudo_random_i64(size, 9);
+        let b = pseudo_random_i64(size, 10);
+        assert_eq!(
+            convolution(&a, &b),
+            convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b)
+        );
+        assert_eq!(linear_convolution(&a, &b), naive_linear_convolution(&a, &b));
+    }
+}
+
+use crate::polynomial::RootFindingOptions;
+
+fn sorted_roots(poly: &Polynomial<Complex<f64>>) -> Vec<crate::polynomial::Root> {
+    let mut roots = poly.roots(&RootFindingOptions::default()).unwrap();
+    roots.sort_by(|a, b| {
+        (a.value.real(), a.value.imag())
+            .partial_cmp(&(b.value.real(), b.value.imag()))
+            .unwrap()
+    });
+    roots
+}
+
+#[test]
+fn roots_polynomial() {
+    // (x - 1)(x - 2)(x - 3) = x³ - 6x² + 11x - 6
+    let poly = Polynomial::new(&[
+        Complex::<f64>::from(-6.0),
+        Complex::<f64>::from(11.0),
+        Complex::<f64>::from(-6.0),
+        Complex::<f64>::from(1.0),
+    ]);
+
+    let roots = sorted_roots(&poly);
+    assert_eq!(roots.len(), 3);
+    for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+        assert_eq!(root.multiplicity, 1);
+        assert!((root.value - Complex::<f64>::from(expected)).abs() < 1e-10);
+        assert!(root.error < 1e-10);
+    }
+}
+
+#[test]
+fn roots_polynomial_complex() {
+    // 2x² + 2 has roots ±i
+    let poly = Polynomial::new(&[
+        Complex::<f64>::from(2.0),
+        Complex::<f64>::from(0.0),
+        Complex::<f64>::from(2.0),
+    ]);
+
+    let roots = sorted_roots(&poly);
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].value - Complex::new(0.0, -1.0)).abs() < 1e-10);
+    assert!((roots[1].value - Complex::new(0.0, 1.0)).abs() < 1e-10);
+}
+
+#[test]
+fn roots_polynomial_multiplicity() {
+    // (x - 1)²(x + 2) = x³ - 3x + 2
+    let poly = Polynomial::new(&[
+        Complex::<f64>::from(2.0),
+        Complex::<f64>::from(-3.0),
+        Complex::<f64>::from(0.0),
+        Complex::<f64>::from(1.0),
+    ]);
+
+    let roots = sorted_roots(&poly);
+    assert_eq!(roots.len(), 2);
+    assert_eq!(roots[0].multiplicity, 1);
+    assert!((roots[0].value - Complex::<f64>::from(-2.0)).abs() < 1e-10);
+    assert_eq!(roots[1].multiplicity, 2);
+    assert!((roots[1].value - Complex::<f64>::from(1.0)).abs() < 1e-10);
+    assert!(roots[1].error < 1e-10);
+}
+
+#[test]
+fn roots_polynomial_errors() {
+    let zero = Polynomial::new(&[Complex::<f64>::from(0.0)]);
+    match zero.roots(&RootFindingOptions::default()) {
+        Err(PolynomialError::ZeroPolynomial(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+
+    let constant = Polynomial::new(&[Complex::<f64>::from(3.0)]);
+    assert!(constant
+        .roots(&RootFindingOptions::default())
+        .unwrap()
+        .is_empty());
+
+    let options = RootFindingOptions {
+        max_iterations: 1,
+        ..Default::default()
+    };
+    let poly = Polynomial::new(
+        &(0..20)
+            .map(|i| Complex::<f64>::from(i as f64 + 1.0))
+            .collect::<Vec<Complex<f64>>>(),
+    );
+    match poly.roots(&options) {
+        Err(PolynomialError::NotConverged(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn derivative_polynomial() {
+    let poly = Polynomial::<f64>::new(&[1.0, 2.0, 3.0]);
+    assert_eq!(poly.derivative().coefs, vec![2.0, 6.0]);
+    assert_eq!(Polynomial::<f64>::new(&[]).derivative().degree(), None);
+}
+
+use crate::polynomial::SubproductTree;
+
+/// Roots of unity, in bit reversed order: the points of any subtree are then spread evenly on the unit circle,
+/// which keeps the subproduct tree well conditioned
+fn bit_reversed_roots_of_unity(log_size: u32) -> Vec<Complex<f64>> {
+    let size = 1usize << log_size;
+    (0..size)
+        .map(|k| {
+            let index = k.reverse_bits() >> (usize::BITS - log_size);
+            let angle = 2.0 * std::f64::consts::PI * (index as f64) / size as f64;
+            Complex::new(angle.cos(), angle.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn apply_many_polynomial() {
+    const DEG: usize = 300;
+    const NB_POINTS: usize = 512;
+
+    let poly = Polynomial::new(
+        &(0..=DEG)
+            .map(|i| Complex::<f64>::new((i % 7) as f64 - 3.0, (i % 3) as f64))
+            .collect::<Vec<Complex<f64>>>(),
+    );
+    let points = bit_reversed_roots_of_unity(9);
+
+    let values = poly.apply_many(&points);
+    assert_eq!(values.len(), NB_POINTS);
+    for (&x, &y) in points.iter().zip(values.iter()) {
+        assert!((poly.apply(x) - y).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn interpolate_polynomial() {
+    // P(x) = 1 - 2x + x³ goes through (0, 1), (1, 0), (2, 5), (-1, 2)
+    let poly =
+        Polynomial::<f64>::interpolate(&[0.0, 1.0, 2.0, -1.0], &[1.0, 0.0, 5.0, 2.0]).unwrap();
+    let expected = [1.0, -2.0, 0.0, 1.0];
+    assert_eq!(poly.coefs.len(), 4);
+    for (val, expected_val) in poly.coefs.iter().zip(expected.iter()) {
+        assert!((val - expected_val).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn interpolate_polynomial_subproduct_tree() {
+    const NB_POINTS: usize = 256;
+
+    let poly = Polynomial::new(
+        &(0..NB_POINTS)
+            .map(|i| Complex::<f64>::new((i % 5) as f64 - 2.0, (i % 4) as f64))
+            .collect::<Vec<Complex<f64>>>(),
+    );
+    let points = bit_reversed_roots_of_unity(8);
+
+    let tree = SubproductTree::new(&points);
+    let values = tree.evaluate(&poly);
+    let interpolated = tree.interpolate(&values).unwrap();
+    assert_eq!(interpolated.coefs.len(), NB_POINTS);
+    for (a, b) in interpolated.coefs.iter().zip(poly.coefs.iter()) {
+        assert!((*a - *b).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn interpolate_polynomial_errors() {
+    match Polynomial::<f64>::interpolate(&[0.0, 1.0], &[1.0]) {
+        Err(PolynomialError::WrongInputSize(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match Polynomial::<f64>::interpolate(&[0.0, 1.0, 1.0], &[1.0, 2.0, 3.0]) {
+        Err(PolynomialError::DuplicatedPoints(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+use crate::polynomial::{PowerSeries, PowerSeriesError};
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).map(|k| k as f64).product()
+}
+
+#[test]
+fn power_series_inverse() {
+    // 1 / (1 - x) = 1 + x + x² + ...
+    let series = PowerSeries::<f64>::new(&Polynomial::new(&[1.0, -1.0]), 10);
+    let inverse = series.inverse().unwrap();
+    assert_eq!(inverse.order(), 10);
+    for i in 0..10 {
+        assert!((inverse.coef(i).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    let prod = (&series * &inverse).unwrap();
+    assert!((prod.coef(0).unwrap() - 1.0).abs() < 1e-12);
+    for i in 1..10 {
+        assert!(prod.coef(i).unwrap().abs() < 1e-12);
+    }
+}
+
+#[test]
+fn power_series_exp_ln() {
+    // exp(x) = sum of x^n / n!, also on a series long enough to use the fft-based products
+    for order in [20, 1500] {
+        let x = PowerSeries::<f64>::new(&Polynomial::new_monomial(1.0, 1), order);
+        let exp = x.exp().unwrap();
+        for i in 0..order {
+            assert!((exp.coef(i).unwrap() - 1.0 / factorial(i)).abs() < 1e-12);
+        }
+
+        // ln(exp(x)) = x
+        let ln = exp.ln().unwrap();
+        assert!((ln.coef(1).unwrap() - 1.0).abs() < 1e-12);
+        for i in (0..order).filter(|&i| i != 1) {
+            assert!(ln.coef(i).unwrap().abs() < 1e-12);
+        }
+    }
+
+    // exp(2x + 3x²) = 1 + 2x + 5x² + ..., where the fft-based products round the constant term
+    const ORDER: usize = 3000;
+    let f = PowerSeries::<f64>::new(&Polynomial::new(&[0.0, 2.0, 3.0]), ORDER);
+    let exp = f.exp().unwrap();
+    assert_eq!(exp.coef(0).unwrap(), 1.0);
+    assert!((exp.coef(2).unwrap() - 5.0).abs() < 1e-12);
+    let ln = exp.ln().unwrap();
+    for i in 0..ORDER {
+        assert!((ln.coef(i).unwrap() - f.coef(i).unwrap()).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn power_series_sqrt() {
+    // sqrt(1 + x)² = 1 + x, on a series long enough to use the fft-based products
+    const ORDER: usize = 300;
+    let series = PowerSeries::<f64>::new(&Polynomial::new(&[1.0, 1.0]), ORDER);
+    let sqrt = series.sqrt().unwrap();
+    assert!((sqrt.coef(1).unwrap() - 0.5).abs() < 1e-12);
+    assert!((sqrt.coef(2).unwrap() + 0.125).abs() < 1e-12);
+
+    let square = (&sqrt * &sqrt).unwrap();
+    assert!((square.coef(0).unwrap() - 1.0).abs() < 1e-10);
+    assert!((square.coef(1).unwrap() - 1.0).abs() < 1e-10);
+    for i in 2..ORDER {
+        assert!(square.coef(i).unwrap().abs() < 1e-10);
+    }
+}
+
+#[test]
+fn power_series_compose() {
+    // (1 + y)² with y = x + x² gives 1 + 2x + 3x² + 2x³ + x⁴
+    let outer = PowerSeries::<f64>::new(&Polynomial::new(&[1.0, 2.0, 1.0]), 4);
+    let inner = PowerSeries::<f64>::new(&Polynomial::new(&[0.0, 1.0, 1.0]), 4);
+
+    let composed = outer.compose(&inner).unwrap();
+    assert_eq!(composed.polynomial().coefs, vec![1.0, 2.0, 3.0, 2.0]);
+
+    let composed = outer.with_order(6).compose(&inner.with_order(6)).unwrap();
+    assert_eq!(
+        composed.polynomial().coefs,
+        vec![1.0, 2.0, 3.0, 2.0, 1.0, 0.0]
+    );
+}
+
+#[test]
+fn power_series_errors() {
+    let series = PowerSeries::<f64>::new(&Polynomial::new(&[2.0, 1.0]), 4);
+    let other = PowerSeries::<f64>::new(&Polynomial::new(&[2.0, 1.0]), 5);
+
+    match &series + &other {
+        Err(PowerSeriesError::OrderMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match series.ln() {
+        Err(PowerSeriesError::InvalidConstantTerm(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match series.exp() {
+        Err(PowerSeriesError::InvalidConstantTerm(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match series.compose(&series) {
+        Err(PowerSeriesError::InvalidConstantTerm(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match PowerSeries::<f64>::new(&Polynomial::new(&[0.0, 1.0]), 4).inverse() {
+        Err(PowerSeriesError::InvalidConstantTerm(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+    match series.coef(4) {
+        Err(PowerSeriesError::DegreeAboveOrder(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+#[test]
+fn power_series_small_characteristic() {
+    // In Z/17Z, the integral divides by 17 from the order 18
+    type F = Zp<17>;
+    let x = PowerSeries::<F>::new(&Polynomial::new(&[F::new(0), F::new(1)]), 17);
+    let exp = x.exp().unwrap();
+    assert_eq!(exp.coef(3).unwrap() * F::new(6), F::new(1));
+    assert_eq!(exp.ln().unwrap().coef(1).unwrap(), F::new(1));
+
+    let x = x.with_order(20);
+    for result in [x.integral(), x.exp()] {
+        match result {
+            Err(PowerSeriesError::NonInvertibleInteger(_)) => (),
+            _ => panic!("Wrong error type"),
+        };
+    }
+    let one_plus_x = PowerSeries::<F>::new(&Polynomial::new(&[F::new(1), F::new(1)]), 20);
+    match one_plus_x.ln() {
+        Err(PowerSeriesError::NonInvertibleInteger(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+use crate::polynomial::AlgebraicFormat;
+
+#[test]
+fn polynomial_display_empty() {
+    let p = Polynomial::<f32>::new(&[]);
+    let q = Polynomial::<f32>::new(&[1.0, 2.0]);
+
+    assert_eq!(p.to_string(), "[]");
+    assert_eq!(q.to_string(), "[1, 2]");
+}
+
+#[test]
+fn polynomial_algebraic_format() {
+    let format = AlgebraicFormat::default();
+    let real = Polynomial::<f64>::new(&[-4.0, 0.0, 1.0, -1.0, 2.5]);
+    let complex = Polynomial::new(&[
+        Complex::<f64>::new(-4.0, 0.0),
+        Complex::new(1.0, 2.0),
+        Complex::new(0.0, -3.0),
+        Complex::new(0.0, 1.0),
+    ]);
+
+    assert_eq!(real.to_algebraic_string(&format), "2.5x^4 - x^3 + x^2 - 4");
+    assert_eq!(
+        complex.to_algebraic_string(&format),
+        "ix^3 - 3ix^2 + (1+2i)x - 4"
+    );
+    assert_eq!(
+        Polynomial::<f64>::new(&[]).to_algebraic_string(&format),
+        "0"
+    );
+    assert_eq!(
+        Polynomial::<f64>::new(&[0.0, 0.0]).to_algebraic_string(&format),
+        "0"
+    );
+
+    let custom = AlgebraicFormat {
+        variable: "t".to_string(),
+        precision: Some(2),
+    };
+    assert_eq!(
+        Polynomial::<f64>::new(&[1.0 / 3.0, -1.0, 2.0]).to_algebraic_string(&custom),
+        "2.00t^2 - t + 0.33"
+    );
+    assert_eq!(
+        Polynomial::new(&[Complex::<f64>::new(0.5, -0.25)]).to_algebraic_string(&custom),
+        "(0.50-0.25i)"
+    );
+}
+
+#[test]
+fn polynomial_algebraic_parse() {
+    let p: Polynomial<Complex<f64>> = "3x^2 + (1+2i)x - 4".parse().unwrap();
+    assert_eq!(
+        p.to_string(),
+        Polynomial::new(&[
+            Complex::new(-4.0, 0.0),
+            Complex::new(1.0, 2.0),
+            Complex::new(3.0, 0.0)
+        ])
+        .to_string()
+    );
+
+    let p: Polynomial<Complex<f64>> = "-ix^3 + 2i - (0.5-1.5i)*x".parse().unwrap();
+    assert_eq!(
+        p.to_string(),
+        Polynomial::new(&[
+            Complex::new(0.0, 2.0),
+            Complex::new(-0.5, 1.5),
+            Complex::new(0.0, 0.0),
+            Complex::new(0.0, -1.0)
+        ])
+        .to_string()
+    );
+
+    // Terms of the same degree are added, in any order
+    let p: Polynomial<f32> = "x - 2 + 3x + x^2".parse().unwrap();
+    assert_eq!(
+        p.to_string(),
+        Polynomial::new(&[-2.0, 4.0, 1.0]).to_string()
+    );
+
+    let p = Polynomial::<f64>::parse("y^2 - 0.001", "y").unwrap();
+    assert_eq!(
+        p.to_string(),
+        Polynomial::new(&[-0.001, 0.0, 1.0]).to_string()
+    );
+
+    for wrong in ["", "3x^", "x^2 x", "2 + (1+i", "x + + 1", "3z"] {
+        match wrong.parse::<Polynomial<Complex<f64>>>() {
+            Err(PolynomialError::ParseError(_)) => (),
+            _ => panic!("Wrong error type for \"{}\"", wrong),
+        };
+    }
+}
+
+#[test]
+fn polynomial_algebraic_round_trip() {
+    let format = AlgebraicFormat::default();
+    let p = Polynomial::new(&[
+        Complex::<f64>::new(0.0, -1.0),
+        Complex::new(-2.5, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(1.0, -1.0),
+        Complex::new(-1.0, 0.0),
+    ]);
+
+    let parsed: Polynomial<Complex<f64>> = p.to_algebraic_string(&format).parse().unwrap();
+    assert_eq!(parsed.to_string(), p.to_string());
+}
+
+#[test]
+fn modular_arithmetic_algebraic_format() {
+    let format = AlgebraicFormat::default();
+    let poly = Polynomial::<f64>::new(&[1.0, 0.0, -2.0]);
+    let cyclic = ModularArithmeticPolynomial::new(&poly, 4);
+    let negacyclic = ModularArithmeticPolynomial::new_negacyclic(&poly, 4);
+    let quotient =
+        QuotientRingPolynomial::new(&poly, &Polynomial::new(&[1.0, 0.0, 0.0, 1.0])).unwrap();
+
+    assert_eq!(
+        cyclic.to_algebraic_string(&format),
+        "-2x^2 + 1 mod (x^4 - 1)"
+    );
+    assert_eq!(
+        negacyclic.to_algebraic_string(&format),
+        "-2x^2 + 1 mod (x^4 + 1)"
+    );
+    assert_eq!(
+        quotient.to_algebraic_string(&format),
+        "-2x^2 + 1 mod (x^3 + 1)"
+    );
+}
+
+use crate::polynomial::ntt::{linear_convolution_via_ntt, ntt};
+use crate::prime_field::{Zp, P_998244353, P_GOLDILOCKS};
+
+/// Deterministic pseudo random elements of a prime field, with a linear congruential generator
+fn pseudo_random_zp<const P: u64>(size: usize, seed: u64) -> Vec<Zp<P>> {
+    let mut state = seed;
+    (0..size)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Zp::new(state >> 1)
+        })
+        .collect()
+}
+
+#[test]
+fn ntt_round_trip() {
+    let values = pseudo_random_zp::<P_998244353>(64, 1);
+    let root = Zp::<P_998244353>::root_of_unity(6).unwrap();
+
+    let mut transformed = values.clone();
+    ntt(&mut transformed, root);
+    ntt(&mut transformed, root.inverse().unwrap());
+    let size_inverse = Zp::new(64).inverse().unwrap();
+
+    for (x, y) in transformed.iter().zip(values.iter()) {
+        assert_eq!(*x * size_inverse, *y);
+    }
+}
+
+#[test]
+fn ntt_linear_convolution() {
+    for (size_a, size_b) in [(1, 1), (3, 5), (100, 37), (1000, 1500)] {
+        let a = pseudo_random_zp::<P_998244353>(size_a, 2);
+        let b = pseudo_random_zp::<P_998244353>(size_b, 3);
+        assert_eq!(
+            linear_convolution_via_ntt(&a, &b),
+            naive_linear_convolution(&a, &b)
+        );
+
+        let a = pseudo_random_zp::<P_GOLDILOCKS>(size_a, 4);
+        let b = pseudo_random_zp::<P_GOLDILOCKS>(size_b, 5);
+        assert_eq!(
+            linear_convolution_via_ntt(&a, &b),
+            naive_linear_convolution(&a, &b)
+        );
+    }
+
+    // 2 divides 7 - 1, but not 4: larger products fall back to Karatsuba
+    let a = pseudo_random_zp::<7>(40, 6);
+    let b = pseudo_random_zp::<7>(50, 7);
+    assert_eq!(
+        linear_convolution_via_ntt(&a, &b),
+        naive_linear_convolution(&a, &b)
+    );
+}
+
+#[test]
+fn polynomial_mult_prime_field() {
+    // Large enough to go through the ntt
+    let a = Polynomial::new(&pseudo_random_zp::<P_GOLDILOCKS>(1500, 8));
+    let b = Polynomial::new(&pseudo_random_zp::<P_GOLDILOCKS>(1200, 9));
+
+    let product = &a * &b;
+    let expected = naive_linear_convolution(&a.coefs, &b.coefs);
+    assert_eq!(product.coefs, expected);
+}
+
+#[test]
+fn modular_arithmetic_mult_prime_field() {
+    let size = 512;
+    let a_coefs = pseudo_random_zp::<P_998244353>(size, 10);
+    let b_coefs = pseudo_random_zp::<P_998244353>(size, 11);
+
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        let a = ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&a_coefs), size, kind);
+        let b = ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&b_coefs), size, kind);
+        let product = (&a * &b).unwrap();
+
+        // Folding the full product by hand, with exact arithmetic
+        let full = naive_linear_convolution(&a_coefs, &b_coefs);
+        for n in 0..size {
+            let mut expected = full[n];
+            if n + size < full.len() {
+                match kind {
+                    RingKind::Cyclic => expected += full[n + size],
+                    RingKind::Negacyclic => expected -= full[n + size],
+                }
+            }
+            assert_eq!(product.coef(n).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn modular_arithmetic_prime_field_exact() {
+    type F = Zp<P_998244353>;
+    // (x + 2)(x^2 - 1) = x^3 + 2x^2 - x - 2 = 2x^2 - x - 1 mod (x^3 - 1)
+    let a = ModularArithmeticPolynomial::new(&Polynomial::new(&[F::new(2), F::new(1)]), 3);
+    let b =
+        ModularArithmeticPolynomial::new(&Polynomial::new(&[-F::new(1), F::new(0), F::new(1)]), 3);
+    let product = (&a * &b).unwrap();
+
+    assert_eq!(product.coef(0).unwrap(), -F::new(1));
+    assert_eq!(product.coef(1).unwrap(), -F::new(1));
+    assert_eq!(product.coef(2).unwrap(), F::new(2));
+
+    // Division is exact as well
+    let numerator = Polynomial::new(&[-F::new(2), -F::new(1), F::new(2), F::new(1)]);
+    let (q, r) = numerator
+        .div_rem(&Polynomial::new(&[F::new(2), F::new(1)]))
+        .unwrap();
+    assert_eq!(q.coefs, vec![-F::new(1), F::new(0), F::new(1)]);
+    assert!(r.coefs.iter().all(|&c| c == F::new(0)));
+}
+
+#[test]
+fn polynomial_matrix_prime_field() {
+    type F = Zp<P_998244353>;
+    let x_plus_1 = ModularArithmeticPolynomial::new(&Polynomial::new(&[F::new(1), F::new(1)]), 4);
+    let m = crate::matrix::Matrix::new(vec![x_plus_1.clone(); 4], 2, 2).unwrap();
+
+    // Each entry is 2 * (x + 1)^2 = 2x^2 + 4x + 2
+    let square = (&m * &m).unwrap();
+    let entry = &square[(0, 1)];
+    assert_eq!(entry.coef(0).unwrap(), F::new(2));
+    assert_eq!(entry.coef(1).unwrap(), F::new(4));
+    assert_eq!(entry.coef(2).unwrap(), F::new(2));
+    assert_eq!(entry.coef(3).unwrap(), F::new(0));
+}
+
+#[test]
+fn polynomial_integer_coefficients() {
+    // (x + 1)^2 * (x - 1) = x^3 + x^2 - x - 1
+    let a = Polynomial::<i64>::new(&[1, 1]);
+    let b = Polynomial::<i64>::new(&[-1, 1]);
+    let product = &(&a * &a) * &b;
+
+    assert_eq!(product.coefs, vec![-1, -1, 1, 1]);
+    assert_eq!(product.apply(3), 32);
+    assert_eq!(product.derivative().coefs, vec![-1, 2, 3]);
+
+    // Large enough to go through the Karatsuba convolution, with products above the f64 precision
+    let a = Polynomial::<i128>::new(&vec![1 << 40; 200]);
+    let b = Polynomial::<i128>::new(&vec![(1 << 40) + 1; 150]);
+    let product = &a * &b;
+    assert_eq!(product.coefs[149], 150 * (1 << 40) * ((1 << 40) + 1));
+    assert_eq!(product.coefs, naive_linear_convolution(&a.coefs, &b.coefs));
+}
+
+#[test]
+fn modular_arithmetic_integer_coefficients() {
+    let a = ModularArithmeticPolynomial::new(&Polynomial::<i64>::new(&[1, 2, 3]), 3);
+    let b = ModularArithmeticPolynomial::new_negacyclic(&Polynomial::<i64>::new(&[0, 1]), 3);
+    let c = ModularArithmeticPolynomial::new_negacyclic(&Polynomial::<i64>::new(&[1, 2, 3]), 3);
+
+    // x * (1 + 2x + 3x^2) = x + 2x^2 + 3x^3 = -3 + x + 2x^2 mod (x^3 + 1)
+    let product = (&b * &c).unwrap();
+    assert_eq!(product.coef(0).unwrap(), -3);
+    assert_eq!(product.coef(1).unwrap(), 1);
+    assert_eq!(product.coef(2).unwrap(), 2);
+
+    match &a * &b {
+        Err(ModularArithmeticError::RingKindMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    };
+}
+
+use crate::polynomial::fft::{dft, inverse_dft, FftPlan, FftPlanner};
+use std::sync::Arc;
+
+/// Direct evaluation of the discrete Fourier transform, in O(n²)
+fn naive_dft(data: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let size = data.len() as f64;
+    (0..data.len())
+        .map(|k| {
+            let mut sum = Complex::new(0.0, 0.0);
+            for (n, &x) in data.iter().enumerate() {
+                let angle = -2.0 * std::f64::consts::PI * (n * k) as f64 / size;
+                sum += x * Complex::from_polar(1.0, angle);
+            }
+            sum
+        })
+        .collect()
+}
+
+#[test]
+fn fft_plan_matches_dft() {
+    // Powers of 2 (radix 2), smooth sizes and small primes (mixed radix), large prime factors (Bluestein)
+    for size in [
+        1, 2, 4, 8, 16, 32, 64, 3, 5, 6, 7, 12, 30, 45, 49, 100, 37, 97, 134,
+    ] {
+        let data: Vec<Complex<f64>> = (0..size)
+            .map(|n| Complex::new((n as f64 * 0.7).sin(), (n as f64 * 1.3).cos()))
+            .collect();
+        let plan = FftPlan::new(size);
+        assert_eq!(plan.size(), size);
+
+        let mut transformed = data.clone();
+        plan.forward(&mut transformed);
+        for (x, y) in transformed.iter().zip(naive_dft(&data).iter()) {
+            assert!((*x - *y).abs() < 1e-10);
+        }
+
+        plan.inverse(&mut transformed);
+        for (x, y) in transformed.iter().zip(data.iter()) {
+            assert!((*x - *y).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn fft_plan_wrong_size() {
+    let plan = FftPlan::new(12);
+    plan.forward(&mut vec![Complex::new(0.0, 0.0); 16]);
+}
+
+#[test]
+fn dft_arbitrary_sizes() {
+    // 3 * 2^k, primes, and a product of a small and a large prime
+    for size in [3 * 256, 1009, 2 * 331] {
+        let data: Vec<Complex<f64>> = (0..size)
+            .map(|n| Complex::new((n as f64 * 0.21).cos(), (n as f64 * 0.83).sin()))
+            .collect();
+
+        let transformed = dft(&data);
+        for (x, y) in transformed.iter().zip(naive_dft(&data).iter()) {
+            assert!((*x - *y).abs() < 1e-8);
+        }
+        for (x, y) in inverse_dft(&transformed).iter().zip(data.iter()) {
+            assert!((*x - *y).abs() < 1e-12);
+        }
+    }
+    assert!(dft(&[]).is_empty());
+    assert!(inverse_dft(&[]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn fft_plan_zero_size() {
+    let _ = FftPlan::new(0);
+}
+
+#[test]
+fn fft_planner_cache() {
+    let planner = FftPlanner::new();
+    let plan = planner.plan(64);
+
+    assert!(Arc::ptr_eq(&plan, &planner.plan(64)));
+    assert!(!Arc::ptr_eq(&plan, &planner.plan(128)));
+
+    // Plans can be requested and used from several threads at once
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                let plan = planner.plan(256);
+                let mut data = vec![Complex::new(1.0, 0.0); 256];
+                plan.forward(&mut data);
+                assert_eq!(data[0], Complex::new(256.0, 0.0));
+            });
+        }
+    });
+    assert!(Arc::ptr_eq(&planner.plan(256), &planner.plan(256)));
+}
+
+use crate::polynomial::convolution::{
+    convolution_for_polynomial_mult_in_modular_arithmetic, cyclic_convolution_via_fft,
+};
+use crate::polynomial::ntt::cyclic_convolution_via_ntt;
+
+#[test]
+fn cyclic_convolution_fft() {
+    // Power of 2, smooth sizes (direct transform) and a prime size (padded and folded)
+    for size in [1, 256, 384, 300, 211] {
+        let a: Vec<f64> = (0..size).map(|n| (n as f64 * 0.37).sin()).collect();
+        let b: Vec<f64> = (0..size).map(|n| (n as f64 * 0.91).cos()).collect();
+
+        let product = cyclic_convolution_via_fft(&a, &b);
+        let expected = convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b);
+        assert_eq!(product.len(), size);
+        for (x, y) in product.iter().zip(expected.iter()) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn cyclic_convolution_ntt() {
+    // Power of 2 (direct transform) and other sizes (padded and folded)
+    for size in [1, 256, 300] {
+        let a = pseudo_random_zp::<P_998244353>(size, 12);
+        let b = pseudo_random_zp::<P_998244353>(size, 13);
+        assert_eq!(
+            cyclic_convolution_via_ntt(&a, &b),
+            convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b)
+        );
+    }
+}
+
+#[test]
+fn modular_arithmetic_mult_cyclic_fft() {
+    let size = 768;
+    let a_coefs: Vec<Complex<f64>> = (0..size)
+        .map(|n| Complex::new((n as f64 * 0.13).sin(), (n as f64 * 0.29).cos()))
+        .collect();
+    let b_coefs: Vec<Complex<f64>> = (0..size)
+        .map(|n| Complex::new((n as f64 * 0.53).cos(), (n as f64 * 0.07).sin()))
+        .collect();
+    let a = ModularArithmeticPolynomial::new(&Polynomial::new(&a_coefs), size);
+    let b = ModularArithmeticPolynomial::new(&Polynomial::new(&b_coefs), size);
+
+    let product = (&a * &b).unwrap();
+    let expected = convolution_for_polynomial_mult_in_modular_arithmetic(&a_coefs, &b_coefs);
+    for (n, y) in expected.iter().enumerate() {
+        assert!((product.coef(n).unwrap() - *y).abs() < 1e-9);
+    }
+}
+
+use crate::polynomial::convolution::{
+    convolution_with_thresholds, linear_convolution_with_thresholds, ConvolutionAlgorithm,
+    ConvolutionThresholds, FastConvolution,
+};
+use crate::polynomial::tuning;
+
+#[test]
+fn convolution_thresholds_decision() {
+    let thresholds = ConvolutionThresholds {
+        karatsuba: 32,
+        toom3: 128,
+        fast: 512,
+        fast_cyclic: 256,
+    };
+    assert_eq!(thresholds.cyclic_algorithm(32), ConvolutionAlgorithm::Naive);
+    assert_eq!(
+        thresholds.cyclic_algorithm(33),
+        ConvolutionAlgorithm::Karatsuba
+    );
+    assert_eq!(
+        thresholds.cyclic_algorithm(200),
+        ConvolutionAlgorithm::Toom3
+    );
+    assert_eq!(thresholds.cyclic_algorithm(257), ConvolutionAlgorithm::Fast);
+
+    assert_eq!(
+        thresholds.linear_algorithm(0, 1000),
+        ConvolutionAlgorithm::Naive
+    );
+    assert_eq!(
+        thresholds.linear_algorithm(20, 1000),
+        ConvolutionAlgorithm::Naive
+    );
+    // Very unbalanced products use the fast convolution, unless the shortest input is tiny
+    assert_eq!(
+        thresholds.linear_algorithm(2, 100000),
+        ConvolutionAlgorithm::Naive
+    );
+    for shortest in [100, 256] {
+        assert_eq!(
+            f64::THRESHOLDS.linear_algorithm(shortest, 100000),
+            ConvolutionAlgorithm::Fast
+        );
+    }
+    assert_eq!(
+        thresholds.linear_algorithm(100, 100),
+        ConvolutionAlgorithm::Karatsuba
+    );
+    assert_eq!(
+        thresholds.linear_algorithm(200, 300),
+        ConvolutionAlgorithm::Toom3
+    );
+    assert_eq!(
+        thresholds.linear_algorithm(100, 600),
+        ConvolutionAlgorithm::Fast
+    );
+
+    for algorithm in [
+        ConvolutionAlgorithm::Naive,
+        ConvolutionAlgorithm::Karatsuba,
+        ConvolutionAlgorithm::Toom3,
+        ConvolutionAlgorithm::Fast,
+    ] {
+        let forced = ConvolutionThresholds::forcing(algorithm);
+        for size in [1, 100, 10000] {
+            assert_eq!(forced.cyclic_algorithm(size), algorithm);
+            assert_eq!(forced.linear_algorithm(size, size), algorithm);
+        }
+    }
+}
+
+#[test]
+fn convolution_with_forced_algorithm() {
+    let a = pseudo_random_i64(300, 11);
+    let b = pseudo_random_i64(300, 12);
+    let expected_cyclic = convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b);
+    let expected_linear = naive_linear_convolution(&a, &b);
+    for algorithm in [
+        ConvolutionAlgorithm::Naive,
+        ConvolutionAlgorithm::Karatsuba,
+        ConvolutionAlgorithm::Toom3,
+        ConvolutionAlgorithm::Fast,
+    ] {
+        let forced = ConvolutionThresholds::forcing(algorithm);
+        assert_eq!(
+            convolution_with_thresholds(&a, &b, &forced),
+            expected_cyclic
+        );
+        assert_eq!(
+            linear_convolution_with_thresholds(&a, &b, &forced),
+            expected_linear
+        );
+    }
+}
+
+#[test]
+fn convolution_global_thresholds() {
+    // i128 isn't used by any other test of the thresholds, which are global
+    assert_eq!(tuning::thresholds::<i128>(), i128::THRESHOLDS);
+
+    tuning::set_thresholds::<i128>(ConvolutionThresholds::forcing(ConvolutionAlgorithm::Toom3));
+    assert_eq!(
+        tuning::cyclic_algorithm::<i128>(10),
+        ConvolutionAlgorithm::Toom3
+    );
+    assert_eq!(
+        tuning::linear_algorithm::<i128>(10, 20),
+        ConvolutionAlgorithm::Toom3
+    );
+    // Other types keep their thresholds
+    assert_eq!(tuning::thresholds::<f32>(), f32::THRESHOLDS);
+
+    let a = ModularArithmeticPolynomial::new(&Polynomial::new(&[1i128, 2, 3]), 3);
+    let b = ModularArithmeticPolynomial::new(&Polynomial::new(&[4i128, 5, 6]), 3);
+    let product = (&a * &b).unwrap();
+    // (1 + 2x + 3x^2)(4 + 5x + 6x^2) = 4 + 13x + 28x^2 + 27x^3 + 18x^4
+    assert_eq!(product.polynomial.coefs, vec![31, 31, 28]);
+
+    tuning::reset_thresholds::<i128>();
+    assert_eq!(tuning::thresholds::<i128>(), i128::THRESHOLDS);
+}
+
+#[test]
+fn convolution_auto_tune_integers() {
+    // Toom-3 overflows on raw i64, whatever the thresholds: tuning keeps it disabled, and forcing it widens to i128
+    let thresholds = tuning::auto_tune::<i64>(512);
+    assert_eq!(thresholds.toom3, usize::MAX);
+    let a: Vec<i64> = pseudo_random_i64(300, 15)
+        .iter()
+        .map(|x| x * 134_000)
+        .collect();
+    let b: Vec<i64> = pseudo_random_i64(300, 16)
+        .iter()
+        .map(|x| x * 134_000)
+        .collect();
+    let expected = naive_linear_convolution(&a, &b);
+    assert_eq!(linear_convolution(&a, &b), expected);
+    let forced = ConvolutionThresholds::forcing(ConvolutionAlgorithm::Toom3);
+    assert_eq!(
+        linear_convolution_with_thresholds(&a, &b, &forced),
+        expected
+    );
+    assert_eq!(
+        convolution_with_thresholds(&a, &b, &forced),
+        convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b)
+    );
+    tuning::reset_thresholds::<i64>();
+
+    // i128 falls back to Karatsuba
+    let a = vec![(1i128 << 50) - 1; 600];
+    let b = vec![1 - (1i128 << 50); 600];
+    assert_eq!(
+        linear_convolution_with_thresholds(&a, &b, &forced),
+        naive_linear_convolution(&a, &b)
+    );
+}
+
+#[test]
+fn convolution_auto_tune() {
+    type F = Zp<P_GOLDILOCKS>;
+    let thresholds = tuning::auto_tune::<F>(256);
+    assert_eq!(tuning::thresholds::<F>(), thresholds);
+    // The ntt takes over from Karatsuba, without a Toom-3 tier
+    assert_eq!(thresholds.toom3, usize::MAX);
+    for threshold in [
+        thresholds.karatsuba,
+        thresholds.fast,
+        thresholds.fast_cyclic,
+    ] {
+        assert!(threshold <= 256);
+    }
+
+    tuning::reset_thresholds::<F>();
+    assert_eq!(tuning::thresholds::<F>(), F::THRESHOLDS);
+}
+
+use crate::polynomial::convolution::{
+    cyclic_convolution_via_real_fft, linear_convolution_via_real_fft,
+};
+use crate::polynomial::fft::RealFftPlan;
+
+#[test]
+fn real_fft_plan_matches_dft() {
+    // Even sizes use a half size complex plan, odd sizes a full size one
+    for size in [1, 2, 8, 12, 15, 64, 202] {
+        let signal: Vec<f64> = (0..size).map(|n| (n as f64 * 0.7).sin() + 0.5).collect();
+        let complex_signal: Vec<Complex<f64>> =
+            signal.iter().map(|&x| Complex::new(x, 0.0)).collect();
+        let plan = RealFftPlan::new(size);
+        assert_eq!(plan.size(), size);
+
+        let spectrum = plan.forward(&signal);
+        for (x, y) in spectrum.iter().zip(naive_dft(&complex_signal).iter()) {
+            assert!((*x - *y).abs() < 1e-10);
+        }
+        for (x, y) in plan.inverse(&spectrum).iter().zip(signal.iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    // Shorter signals are padded with zeros
+    let plan = RealFftPlan::new(8);
+    let spectrum = plan.forward(&[1.0, 2.0, 3.0]);
+    let expected = naive_dft(&[1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0].map(Complex::from));
+    for (x, y) in spectrum.iter().zip(expected.iter()) {
+        assert!((*x - *y).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn real_fft_planner_cache() {
+    let planner = FftPlanner::new();
+    let plan = planner.real_plan(64);
+    assert!(Arc::ptr_eq(&plan, &planner.real_plan(64)));
+    assert_eq!(plan.size(), 64);
+}
+
+#[test]
+fn real_fft_convolution() {
+    let a: Vec<f64> = (0..1500).map(|n| (n as f64 * 0.37).sin()).collect();
+    let b: Vec<f64> = (0..1100).map(|n| (n as f64 * 0.91).cos()).collect();
+    let product = linear_convolution_via_real_fft(&a, &b);
+    let expected = naive_linear_convolution(&a, &b);
+    assert_eq!(product.len(), expected.len());
+    for (x, y) in product.iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-9);
+    }
+
+    // Power of 2, smooth odd size (direct transform), and a prime size (padded and folded)
+    for size in [256, 135, 211] {
+        let a: Vec<f32> = (0..size).map(|n| (n as f32 * 0.37).sin()).collect();
+        let b: Vec<f32> = (0..size).map(|n| (n as f32 * 0.91).cos()).collect();
+        let product = cyclic_convolution_via_real_fft(&a, &b);
+        let expected = convolution_for_polynomial_mult_in_modular_arithmetic(&a, &b);
+        assert_eq!(product.len(), size);
+        for (x, y) in product.iter().zip(expected.iter()) {
+            assert!((x - y).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn fft_plan_large_sizes() {
+    // Large enough to be split between threads with the parallel feature: radix 2, mixed radix and Bluestein
+    for size in [1 << 15, 3 << 14, 2 * 8209] {
+        let frequency = 5;
+        let data: Vec<Complex<f64>> = (0..size)
+            .map(|n| {
+                let angle = 2.0 * std::f64::consts::PI * (frequency * n) as f64 / size as f64;
+                Complex::new(angle.cos(), 0.0)
+            })
+            .collect();
+        let plan = FftPlan::new(size);
+
+        // The transform of a cosine has two peaks of height size / 2
+        let mut transformed = data.clone();
+        plan.forward(&mut transformed);
+        for (k, x) in transformed.iter().enumerate() {
+            let expected = if k == frequency || k == size - frequency {
+                size as f64 / 2.0
+            } else {
+                0.0
+            };
+            assert!((*x - Complex::new(expected, 0.0)).abs() < 1e-6);
+        }
+
+        // Results don't depend on the scheduling of the threads
+        let mut again = data.clone();
+        plan.forward(&mut again);
+        assert_eq!(transformed, again);
+    }
+}
+
+#[test]
+fn evaluate_prime_field() {
+    type F = Zp<P_998244353>;
+    let size = 16;
+    let a_coefs = pseudo_random_zp::<P_998244353>(size, 20);
+    let b_coefs = pseudo_random_zp::<P_998244353>(size, 21);
+
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        let a = ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&a_coefs), size, kind);
+        let b = ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&b_coefs), size, kind);
+        let a_values = a.evaluate().unwrap();
+
+        // The values are taken at roots of the modulus, in the order of the ntt: the second one is at w or psi * w,
+        // where w = psi^2 has order 16
+        let psi = F::root_of_unity(5).unwrap();
+        let (point, power) = match kind {
+            RingKind::Cyclic => (psi * psi, F::new(1)),
+            RingKind::Negacyclic => (psi * psi * psi, -F::new(1)),
+        };
+        assert_eq!(point.pow(size as u64), power);
+        assert_eq!(a_values[1], a.apply(point));
+
+        // Interpolation is the exact inverse, and pointwise products are ring products
+        let back = ModularArithmeticPolynomial::<F>::interpolate(&a_values, kind);
+        let b_values = b.evaluate().unwrap();
+        let product_values: Vec<F> = a_values
+            .iter()
+            .zip(b_values.iter())
+            .map(|(&x, &y)| x * y)
+            .collect();
+        let product = ModularArithmeticPolynomial::<F>::interpolate(&product_values, kind);
+        let expected = (&a * &b).unwrap();
+        for n in 0..size {
+            assert_eq!(back.coef(n).unwrap(), a.coef(n).unwrap());
+            assert_eq!(product.coef(n).unwrap(), expected.coef(n).unwrap());
+        }
+        assert_eq!(product.kind(), kind);
+    }
+}
+
+#[test]
+fn evaluate_floats() {
+    for size in [1, 6, 16, 25] {
+        let a_coefs: Vec<f64> = (0..size).map(|n| ((n * 7) as f64).sin()).collect();
+        let b_coefs: Vec<f64> = (0..size).map(|n| ((n * 3) as f64).cos()).collect();
+
+        for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+            let a =
+                ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&a_coefs), size, kind);
+            let b =
+                ModularArithmeticPolynomial::new_with_kind(&Polynomial::new(&b_coefs), size, kind);
+            let product_values: Vec<Complex<f64>> = a
+                .evaluate()
+                .unwrap()
+                .iter()
+                .zip(b.evaluate().unwrap().iter())
+                .map(|(&x, &y)| x * y)
+                .collect();
+            let product = ModularArithmeticPolynomial::<f64>::interpolate(&product_values, kind);
+            let expected = (&a * &b).unwrap();
+            assert_eq!(product.modulus(), size);
+            for n in 0..size {
+                assert!((product.coef(n).unwrap() - expected.coef(n).unwrap()).abs() < 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluate_error() {
+    // The ntt needs a power of 2 modulus
+    let a = ModularArithmeticPolynomial::new(
+        &Polynomial::new(&pseudo_random_zp::<P_998244353>(6, 1)),
+        6,
+    );
+    match a.evaluate() {
+        Err(ModularArithmeticError::NoEvaluationDomain(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
+
+use crate::polynomial::EvaluationForm;
+
+#[test]
+fn evaluation_form_prime_field() {
+    type F = Zp<P_998244353>;
+    let size = 64;
+    for kind in [RingKind::Cyclic, RingKind::Negacyclic] {
+        let a = ModularArithmeticPolynomial::new_with_kind(
+            &Polynomial::new(&pseudo_random_zp::<P_998244353>(size, 30)),
+            size,
+            kind,
+        );
+        let b = ModularArithmeticPolynomial::new_with_kind(
+            &Polynomial::new(&pseudo_random_zp::<P_998244353>(size, 31)),
+            size,
+            kind,
+        );
+        let a_form = EvaluationForm::new(&a).unwrap();
+        let b_form = EvaluationForm::try_from(&b).unwrap();
+        assert_eq!(a_form.modulus(), size);
+        assert_eq!(a_form.kind(), kind);
+
+        let check = |form: &EvaluationForm<F>, expected: &ModularArithmeticPolynomial<F>| {
+            let poly = ModularArithmeticPolynomial::from(form);
+            assert_eq!(poly.kind(), kind);
+            for n in 0..size {
+                assert_eq!(poly.coef(n).unwrap(), expected.coef(n).unwrap());
+            }
+        };
+
+        // The conversions are lossless, and operations match the ones in coefficient representation
+        check(&a_form, &a);
+        check(&(&a_form * &b_form).unwrap(), &(&a * &b).unwrap());
+        check(&(&a_form + &b_form).unwrap(), &(&a + &b).unwrap());
+        check(&(&a_form - &b_form).unwrap(), &(&a - &b).unwrap());
+        check(&-&a_form, &-&a);
+        let product = (&a_form * &b).unwrap();
+        check(&EvaluationForm::new(&product).unwrap(), &(&a * &b).unwrap());
+    }
+}
+
+#[test]
+fn evaluation_form_floats() {
+    let size = 48;
+    let key_coefs: Vec<f64> = (0..size).map(|n| ((n * 5) as f64).sin()).collect();
+    let key = ModularArithmeticPolynomial::new_negacyclic(&Polynomial::new(&key_coefs), size);
+    let key_form = EvaluationForm::new(&key).unwrap();
+
+    // Roundtrip up to rounding errors
+    let back = key_form.to_polynomial();
+    for (n, &coef) in key_coefs.iter().enumerate() {
+        assert!((back.coef(n).unwrap() - coef).abs() < 1e-12);
+    }
+
+    // The same key multiplies many polynomials
+    for seed in 0..5 {
+        let coefs: Vec<f64> = (0..size).map(|n| ((n + seed * 100) as f64).cos()).collect();
+        let poly = ModularArithmeticPolynomial::new_negacyclic(&Polynomial::new(&coefs), size);
+        let product = (&key_form * &poly).unwrap();
+        let expected = (&key * &poly).unwrap();
+        for n in 0..size {
+            assert!((product.coef(n).unwrap() - expected.coef(n).unwrap()).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn evaluation_form_error() {
+    let a = EvaluationForm::new(&ModularArithmeticPolynomial::<f64>::new_zero(8)).unwrap();
+    let b = EvaluationForm::new(&ModularArithmeticPolynomial::<f64>::new_zero(16)).unwrap();
+    let c = ModularArithmeticPolynomial::<f64>::new_zero_with_kind(8, RingKind::Negacyclic);
+
+    match &a * &b {
+        Err(ModularArithmeticError::ModulusMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &a + &EvaluationForm::new(&c).unwrap() {
+        Err(ModularArithmeticError::RingKindMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    match &a * &c {
+        Err(ModularArithmeticError::RingKindMismatched(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+    let d = ModularArithmeticPolynomial::new(&Polynomial::new(&[Zp::<P_998244353>::new(1)]), 3);
+    match EvaluationForm::new(&d) {
+        Err(ModularArithmeticError::NoEvaluationDomain(_)) => (),
+        _ => panic!("Wrong error type"),
+    }
+}
@@ -0,0 +1,188 @@
+//! This module makes the thresholds of the dispatching convolutions configurable at runtime.
+//!
+//! The default thresholds of each coefficient type (see FastConvolution::THRESHOLDS) come from timings on a single
+//! machine. They can be replaced for the whole process with set_thresholds, or measured on the current machine with
+//! auto_tune. The convolutions taking explicit thresholds bypass the global ones for a single call, and
+//! ConvolutionThresholds::forcing selects one algorithm whatever the size.
+
+use crate::algebra::Ring;
+use crate::polynomial::convolution::{
+    convolution_with_thresholds, karatsuba_linear_convolution, linear_convolution_with_thresholds,
+    naive_linear_convolution, ConvolutionAlgorithm, ConvolutionThresholds, FastConvolution,
+};
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+/// Thresholds set at runtime, keyed by coefficient type
+fn overrides() -> &'static RwLock<HashMap<TypeId, ConvolutionThresholds>> {
+    static OVERRIDES: OnceLock<RwLock<HashMap<TypeId, ConvolutionThresholds>>> = OnceLock::new();
+    OVERRIDES.get_or_init(Default::default)
+}
+
+/// Thresholds currently used by the dispatching convolutions for the coefficient type
+pub fn thresholds<T: FastConvolution>() -> ConvolutionThresholds {
+    overrides()
+        .read()
+        .unwrap()
+        .get(&TypeId::of::<T>())
+        .copied()
+        .unwrap_or(T::THRESHOLDS)
+}
+
+/// Replaces the thresholds of the coefficient type, for the whole process
+pub fn set_thresholds<T: FastConvolution>(thresholds: ConvolutionThresholds) {
+    overrides()
+        .write()
+        .unwrap()
+        .insert(TypeId::of::<T>(), thresholds);
+}
+
+/// Restores the default thresholds of the coefficient type
+pub fn reset_thresholds<T: FastConvolution>() {
+    overrides().write().unwrap().remove(&TypeId::of::<T>());
+}
+
+/// Algorithm used by the multiplication modulo x^n - 1 for the coefficient type, with the current thresholds
+pub fn cyclic_algorithm<T: FastConvolution>(size: usize) -> ConvolutionAlgorithm {
+    thresholds::<T>().cyclic_algorithm(size)
+}
+
+/// Algorithm used by the full polynomial multiplication for the coefficient type, with the current thresholds
+pub fn linear_algorithm<T: FastConvolution>(a_size: usize, b_size: usize) -> ConvolutionAlgorithm {
+    thresholds::<T>().linear_algorithm(a_size, b_size)
+}
+
+/// Sizes at which the algorithms are timed
+const TUNING_SIZES: [usize; 17] = [
+    16, 24, 32, 48, 64, 96, 128, 192, 256, 384, 512, 768, 1024, 1536, 2048, 3072, 4096,
+];
+/// Number of timing runs for each algorithm and size, of which the fastest is kept
+const TUNING_RUNS: usize = 3;
+/// Minimum duration of a timing run, which repeats the convolution as many times as needed
+const TUNING_RUN_DURATION: Duration = Duration::from_millis(1);
+
+/// Measures the thresholds of the coefficient type on the current machine, and sets them for the whole process.
+///
+/// Each algorithm is timed against the previous one at increasing sizes up to max_size, and its threshold is the
+/// last size before it becomes faster. The fast convolutions are compared to the best of the other algorithms. An
+/// algorithm which is never faster up to max_size gets max_size as threshold, since it is asymptotically faster.
+/// Types whose default toom3 threshold is usize::MAX keep it, since they never run Toom-3. This takes from a fraction of a second to a few seconds, depending on max_size.
+pub fn auto_tune<T: FastConvolution>(max_size: usize) -> ConvolutionThresholds {
+    let sizes: Vec<usize> = TUNING_SIZES
+        .iter()
+        .copied()
+        .filter(|&size| size <= max_size)
+        .collect();
+
+    let karatsuba = crossover(
+        &sizes,
+        max_size,
+        naive_linear_convolution::<T>,
+        karatsuba_linear_convolution::<T>,
+    );
+    // Toom-3 is only compared to Karatsuba where the latter is used, and stays disabled for the types which disable it
+    let toom3 = if T::THRESHOLDS.toom3 == usize::MAX {
+        usize::MAX
+    } else {
+        let toom3_sizes: Vec<usize> = sizes
+            .iter()
+            .copied()
+            .filter(|&size| size > karatsuba)
+            .collect();
+        karatsuba.max(crossover(
+            &toom3_sizes,
+            max_size,
+            karatsuba_linear_convolution::<T>,
+            T::toom3_linear_convolution,
+        ))
+    };
+
+    let without_fast = ConvolutionThresholds {
+        karatsuba,
+        toom3,
+        fast: usize::MAX,
+        fast_cyclic: usize::MAX,
+    };
+    let fast = crossover(
+        &sizes,
+        max_size,
+        |a, b| linear_convolution_with_thresholds(a, b, &without_fast),
+        T::fast_linear_convolution,
+    );
+    let fast_cyclic = crossover(
+        &sizes,
+        max_size,
+        |a, b| convolution_with_thresholds(a, b, &without_fast),
+        T::fast_cyclic_convolution,
+    );
+
+    let thresholds = ConvolutionThresholds {
+        karatsuba,
+        toom3,
+        fast,
+        fast_cyclic,
+    };
+    set_thresholds::<T>(thresholds);
+    thresholds
+}
+
+/// Last size before the one from which the second algorithm is faster, at two consecutive sizes to filter out noise
+fn crossover<T: Ring>(
+    sizes: &[usize],
+    max_size: usize,
+    first: impl Fn(&[T], &[T]) -> Vec<T>,
+    second: impl Fn(&[T], &[T]) -> Vec<T>,
+) -> usize {
+    let second_is_faster = |size: usize| {
+        let a = tuning_input::<T>(size, 1);
+        let b = tuning_input::<T>(size, 2);
+        time(&second, &a, &b) < time(&first, &a, &b)
+    };
+
+    // Largest size at which the second algorithm is slower, before it becomes faster
+    let mut last_loss = 0;
+    let mut previous_win = false;
+    for &size in sizes {
+        if second_is_faster(size) {
+            if previous_win {
+                return last_loss;
+            }
+            previous_win = true;
+        } else {
+            last_loss = size;
+            previous_win = false;
+        }
+    }
+    if previous_win {
+        // Faster at the largest size only
+        last_loss
+    } else {
+        max_size
+    }
+}
+
+/// Small pseudo random integers, which are exact in any coefficient type
+fn tuning_input<T: Ring>(size: usize, seed: i64) -> Vec<T> {
+    (0..size as i64)
+        .map(|n| T::from_integer((n * 7919 + seed * 104729) % 201 - 100))
+        .collect()
+}
+
+/// Time of a single convolution, as the fastest of several runs
+fn time<T: Ring>(convolution: &impl Fn(&[T], &[T]) -> Vec<T>, a: &[T], b: &[T]) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..TUNING_RUNS {
+        let start = Instant::now();
+        let mut iterations = 0;
+        while start.elapsed() < TUNING_RUN_DURATION {
+            black_box(convolution(black_box(a), black_box(b)));
+            iterations += 1;
+        }
+        best = best.min(start.elapsed() / iterations);
+    }
+    best
+}
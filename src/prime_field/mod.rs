@@ -0,0 +1,250 @@
+//! This module implements arithmetic in the prime field Z/pZ, with the modulus p as a generic parameter.
+//! Computations are exact, which makes prime fields suitable coefficients for coding theory or cryptography.
+//!
+//! Elements are stored in Montgomery form: x is represented by x * 2^64 mod p. Products then only need a
+//! Montgomery reduction, made of multiplications and shifts, instead of a costly division by p.
+//!
+//! Some primes are especially well suited for polynomial multiplication: when 2^k divides p - 1, the field
+//! has roots of unity of order 2^k, and polynomial products can use the number theoretic transform.
+
+#[cfg(test)]
+mod test;
+
+use crate::algebra::{CommutativeRing, Field, Ring};
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 119 * 2^23 + 1, which allows transforms up to size 2^23
+pub const P_998244353: u64 = 998_244_353;
+/// 2^64 - 2^32 + 1, which allows transforms up to size 2^32
+pub const P_GOLDILOCKS: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Type representing an element of Z/PZ, where P must be an odd prime number.
+/// The Montgomery form of the value is stored, always in [0, P).
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct Zp<const P: u64> {
+    v: u64,
+}
+
+impl<const P: u64> Zp<P> {
+    /// -P^(-1) mod 2^64, needed by the Montgomery reduction. It is computed with Newton iterations, each one
+    /// doubling the number of correct bits, starting from P which is its own inverse mod 2^3.
+    const P_INV_NEG: u64 = {
+        assert!(P % 2 == 1, "Montgomery arithmetic needs an odd modulus");
+        let mut inv = P;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(P.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+    /// 2^128 mod P, which converts values to the Montgomery form
+    const R2: u64 = {
+        let r = (u64::MAX % P + 1) % P;
+        ((r as u128 * r as u128) % P as u128) as u64
+    };
+
+    /// Montgomery reduction: returns x * 2^(-64) mod P, for x < P * 2^64
+    fn reduce(x: u128) -> u64 {
+        let m = (x as u64).wrapping_mul(Self::P_INV_NEG);
+        // x + m * P is a multiple of 2^64, and can overflow u128 when P is close to 2^64
+        let (sum, overflow) = x.overflowing_add(m as u128 * P as u128);
+        let ret = (sum >> 64) | ((overflow as u128) << 64);
+        if ret >= P as u128 {
+            (ret - P as u128) as u64
+        } else {
+            ret as u64
+        }
+    }
+
+    /// Creates an element from any integer, which is reduced mod P
+    pub fn new(value: u64) -> Self {
+        Self {
+            v: Self::reduce((value % P) as u128 * Self::R2 as u128),
+        }
+    }
+
+    /// Public getter for the value, in [0, P)
+    pub fn value(&self) -> u64 {
+        Self::reduce(self.v as u128)
+    }
+
+    /// Exponentiation by squaring
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut ret = Self::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        ret
+    }
+
+    /// Multiplicative inverse, computed with Fermat's little theorem. Zero has no inverse.
+    pub fn inverse(self) -> Option<Self> {
+        if self.v == 0 {
+            return None;
+        }
+        Some(self.pow(P - 2))
+    }
+
+    /// Largest k such that 2^k divides P - 1: the field has roots of unity of order 2^k
+    pub fn two_adicity() -> u32 {
+        (P - 1).trailing_zeros()
+    }
+
+    /// A primitive root of unity of order 2^log_size, if the field has one.
+    ///
+    /// For any x, x^((P - 1) / 2^s) has an order dividing 2^s, where s is the two adicity. The order is exactly
+    /// 2^s when the value, squared s - 1 times, is -1. Such an x is found after a couple of tries, since half of the
+    /// field elements qualify.
+    pub fn root_of_unity(log_size: u32) -> Option<Self> {
+        let adicity = Self::two_adicity();
+        if log_size > adicity {
+            return None;
+        }
+        if log_size == 0 {
+            return Some(Self::new(1));
+        }
+
+        let minus_one = -Self::new(1);
+        let mut candidate = 2;
+        while candidate < P {
+            let root = Self::new(candidate).pow((P - 1) >> adicity);
+            if root.pow(1 << (adicity - 1)) == minus_one {
+                return Some(root.pow(1 << (adicity - log_size)));
+            }
+            candidate += 1;
+        }
+        None
+    }
+}
+
+/// Implement the Display trait
+impl<const P: u64> std::fmt::Display for Zp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// Implement the Debug trait, showing the value instead of its Montgomery form
+impl<const P: u64> std::fmt::Debug for Zp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} mod {}", self.value(), P)
+    }
+}
+
+/// Constructor from u64
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(t: u64) -> Self {
+        Self::new(t)
+    }
+}
+
+/// Constructor from i64, negative values being mapped to P - |t|
+impl<const P: u64> From<i64> for Zp<P> {
+    fn from(t: i64) -> Self {
+        let abs = Self::new(t.unsigned_abs());
+        if t < 0 {
+            -abs
+        } else {
+            abs
+        }
+    }
+}
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Zp<P>;
+
+    fn add(self, other: Zp<P>) -> Self {
+        let (sum, overflow) = self.v.overflowing_add(other.v);
+        if overflow || sum >= P {
+            Self {
+                v: sum.wrapping_sub(P),
+            }
+        } else {
+            Self { v: sum }
+        }
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Zp<P>;
+
+    fn sub(self, other: Zp<P>) -> Self {
+        if self.v >= other.v {
+            Self {
+                v: self.v - other.v,
+            }
+        } else {
+            Self {
+                v: self.v.wrapping_sub(other.v).wrapping_add(P),
+            }
+        }
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Zp<P>;
+
+    fn mul(self, other: Zp<P>) -> Self {
+        Self {
+            v: Self::reduce(self.v as u128 * other.v as u128),
+        }
+    }
+}
+
+/// Division by the inverse. As for integer types, dividing by zero panics.
+impl<const P: u64> Div for Zp<P> {
+    type Output = Zp<P>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Zp<P>) -> Self {
+        self * other.inverse().expect("Division by zero in Z/PZ")
+    }
+}
+
+impl<const P: u64> AddAssign for Zp<P> {
+    fn add_assign(&mut self, other: Zp<P>) {
+        *self = *self + other;
+    }
+}
+
+impl<const P: u64> SubAssign for Zp<P> {
+    fn sub_assign(&mut self, other: Zp<P>) {
+        *self = *self - other;
+    }
+}
+
+impl<const P: u64> MulAssign for Zp<P> {
+    fn mul_assign(&mut self, other: Zp<P>) {
+        *self = *self * other;
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(0) - self
+    }
+}
+
+impl<const P: u64> Ring for Zp<P> {
+    fn zero() -> Self {
+        Self { v: 0 }
+    }
+    fn one() -> Self {
+        Self::new(1)
+    }
+    fn from_integer(n: i64) -> Self {
+        Self::from(n)
+    }
+}
+impl<const P: u64> CommutativeRing for Zp<P> {}
+impl<const P: u64> Field for Zp<P> {}
+impl<const P: u64> crate::matrix::MatrixInput for Zp<P> {}
@@ -0,0 +1,111 @@
+use crate::prime_field::{Zp, P_998244353, P_GOLDILOCKS};
+
+type F = Zp<P_998244353>;
+type G = Zp<P_GOLDILOCKS>;
+
+#[test]
+fn zp_new() {
+    let a = F::new(P_998244353 + 5);
+
+    assert_eq!(a.value(), 5);
+    assert_eq!(F::from(-1i64).value(), P_998244353 - 1);
+    assert_eq!(F::from(-3i64).value(), P_998244353 - 3);
+    assert_eq!(F::from(7u64).value(), 7);
+}
+
+#[test]
+fn zp_arithmetic() {
+    let a = F::new(P_998244353 - 2);
+    let b = F::new(5);
+
+    assert_eq!((a + b).value(), 3);
+    assert_eq!((b - a).value(), 7);
+    assert_eq!((a * b).value(), P_998244353 - 10);
+    assert_eq!((-b).value(), P_998244353 - 5);
+    assert_eq!((-F::new(0)).value(), 0);
+}
+
+#[test]
+fn zp_arithmetic_near_u64_max() {
+    let a = G::new(P_GOLDILOCKS - 1);
+    let b = G::new(P_GOLDILOCKS - 2);
+
+    // Sums overflow u64
+    assert_eq!((a + b).value(), P_GOLDILOCKS - 3);
+    assert_eq!((a * b).value(), 2);
+    assert_eq!((b - a).value(), P_GOLDILOCKS - 1);
+}
+
+#[test]
+fn zp_pow_inverse() {
+    let a = F::new(3);
+
+    assert_eq!(a.pow(0).value(), 1);
+    assert_eq!(a.pow(5).value(), 243);
+    assert_eq!(a.pow(P_998244353 - 1).value(), 1);
+    assert_eq!((a * a.inverse().unwrap()).value(), 1);
+    assert!(F::new(0).inverse().is_none());
+
+    let b = G::new(123456789);
+    assert_eq!((b * b.inverse().unwrap()).value(), 1);
+}
+
+#[test]
+fn zp_root_of_unity() {
+    assert_eq!(F::two_adicity(), 23);
+    assert_eq!(G::two_adicity(), 32);
+
+    for log_size in [0, 1, 5, 23] {
+        let root = F::root_of_unity(log_size).unwrap();
+        assert_eq!(root.pow(1 << log_size).value(), 1);
+        if log_size > 0 {
+            assert_eq!(root.pow(1 << (log_size - 1)).value(), P_998244353 - 1);
+        }
+    }
+    assert!(F::root_of_unity(24).is_none());
+
+    let root = G::root_of_unity(32).unwrap();
+    assert_eq!(root.pow(1 << 31).value(), P_GOLDILOCKS - 1);
+}
+
+#[test]
+fn zp_montgomery_matches_naive_reduction() {
+    let mut state = 12345u64;
+    for _ in 0..1000 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let x = state;
+        let y = state.rotate_left(17);
+
+        let expected = (x as u128 % P_GOLDILOCKS as u128) * (y as u128 % P_GOLDILOCKS as u128)
+            % P_GOLDILOCKS as u128;
+        assert_eq!((G::new(x) * G::new(y)).value() as u128, expected);
+
+        let expected = (x % P_998244353) * (y % P_998244353) % P_998244353;
+        assert_eq!((F::new(x) * F::new(y)).value(), expected);
+    }
+}
+
+#[test]
+fn zp_div() {
+    let a = F::new(10);
+    let b = F::new(4);
+
+    assert_eq!((a / b) * b, a);
+    assert_eq!((F::new(1) / F::new(2)).value(), P_998244353 / 2 + 1);
+}
+
+#[test]
+#[should_panic]
+fn zp_div_by_zero() {
+    let _ = F::new(1) / F::new(0);
+}
+
+#[test]
+fn zp_display() {
+    let a = Zp::<7>::new(12);
+
+    assert_eq!(a.to_string(), "5");
+    assert_eq!(format!("{:?}", a), "5 mod 7");
+}
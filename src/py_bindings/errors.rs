@@ -37,6 +37,21 @@ impl From<polynomial::ModularArithmeticError> for pyo3::PyErr {
             polynomial::ModularArithmeticError::DegreeAboveModulus(s) => {
                 py_value_error::<polynomial::ModularArithmeticError>(&s)
             }
+            polynomial::ModularArithmeticError::RingKindMismatched(s) => {
+                py_value_error::<polynomial::ModularArithmeticError>(&s)
+            }
+            polynomial::ModularArithmeticError::ModulusPolynomialMismatched(s) => {
+                py_value_error::<polynomial::ModularArithmeticError>(&s)
+            }
+            polynomial::ModularArithmeticError::ModulusNotMonic(s) => {
+                py_value_error::<polynomial::ModularArithmeticError>(&s)
+            }
+            polynomial::ModularArithmeticError::NotInvertible(s) => {
+                py_value_error::<polynomial::ModularArithmeticError>(&s)
+            }
+            polynomial::ModularArithmeticError::NoEvaluationDomain(s) => {
+                py_value_error::<polynomial::ModularArithmeticError>(&s)
+            }
         }
     }
 }
@@ -60,6 +75,7 @@ impl From<matrix::MatrixError> for pyo3::PyErr {
             }
             matrix::MatrixError::OutOfBoundsIndex(s) => py_value_error::<matrix::MatrixError>(&s),
             matrix::MatrixError::ModularArithmeticError(s) => pyo3::PyErr::from(s),
+            matrix::MatrixError::Singular(s) => py_value_error::<matrix::MatrixError>(&s),
         }
     }
 }
@@ -47,6 +47,73 @@ impl Complex {
             val: self.val * other.val,
         })
     }
+    /// Binding of subtraction
+    pub fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val - other.val,
+        })
+    }
+    /// Binding of division
+    pub fn __truediv__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val / other.val,
+        })
+    }
+    /// Binding of negation
+    pub fn __neg__(&self) -> PyResult<Self> {
+        Ok(Self { val: -self.val })
+    }
+    /// Binding of the power with a real exponent. As for Python complex numbers, there is no modular power.
+    pub fn __pow__(&self, exponent: f64, modulo: Option<&PyAny>) -> PyResult<Self> {
+        if modulo.is_some() {
+            return Err(PyValueError::new_err("complex modulo"));
+        }
+        Ok(Self {
+            val: self.val.powf(exponent),
+        })
+    }
+    /// Binding of the modulus
+    pub fn __abs__(&self) -> PyResult<f64> {
+        Ok(self.val.abs())
+    }
+
+    /// Constructor from modulus and argument
+    #[staticmethod]
+    pub fn from_polar(modulus: f64, argument: f64) -> PyResult<Self> {
+        Ok(Self {
+            val: complex::Complex::<f64>::from_polar(modulus, argument),
+        })
+    }
+    /// Modulus and argument
+    pub fn to_polar(&self) -> PyResult<(f64, f64)> {
+        Ok(self.val.to_polar())
+    }
+    /// Argument, in [-pi, pi]
+    pub fn arg(&self) -> PyResult<f64> {
+        Ok(self.val.arg())
+    }
+    /// Complex conjugate
+    pub fn conj(&self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val.conj(),
+        })
+    }
+    /// Exponential
+    pub fn exp(&self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val.exp(),
+        })
+    }
+    /// Principal value of the natural logarithm
+    pub fn ln(&self) -> PyResult<Self> {
+        Ok(Self { val: self.val.ln() })
+    }
+    /// Principal value of the square root
+    pub fn sqrt(&self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val.sqrt(),
+        })
+    }
 
     /// Getter, via index, for real part or imaginary part
     pub fn __getitem__(&self, n: usize) -> PyResult<f64> {
@@ -187,6 +254,34 @@ impl Matrix {
         Ok(self.val[t] = complex::Complex::<f64>::from(c))
     }
 
+    /// LU decomposition with partial pivoting, as the matrices (P, L, U) such that P * self = L * U
+    pub fn lu(&self) -> PyResult<(Self, Self, Self)> {
+        let lu = self.val.lu()?;
+        Ok((
+            Self {
+                val: lu.permutation(),
+            },
+            Self { val: lu.lower() },
+            Self { val: lu.upper() },
+        ))
+    }
+    /// Determinant of a square matrix
+    pub fn det(&self) -> PyResult<Complex> {
+        Ok(Complex::from(self.val.det()?))
+    }
+    /// Solution x of self * x = b
+    pub fn solve(&self, b: &Self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val.solve(&b.val)?,
+        })
+    }
+    /// Inverse of a square matrix
+    pub fn inverse(&self) -> PyResult<Self> {
+        Ok(Self {
+            val: self.val.inverse()?,
+        })
+    }
+
     /// Bind string conversion for Python
     pub fn __str__(&self) -> PyResult<String> {
         return Ok(self.val.to_string());
@@ -215,10 +310,10 @@ impl PolynomialMatrix {
             val: (&self.val + &other.val)?,
         })
     }
-    /// Binding of multiplication
+    /// Binding of multiplication, computed in point-value representation
     pub fn __mul__(&self, other: &Self) -> PyResult<Self> {
         Ok(Self {
-            val: (&self.val * &other.val)?,
+            val: self.val.evaluation_product(&other.val)?,
         })
     }
 